@@ -27,36 +27,41 @@ pub enum ConfigCommand {
 /// Validate command arguments.
 #[derive(Parser, Clone, Debug)]
 pub struct ValidateArgs {
-    /// Config file path (defaults to ~/.config/pdb-sync/config.toml)
-    #[arg(short, long)]
+    /// Config file path (defaults to ~/.config/pdb-sync/config.toml; `--config` also works)
+    #[arg(short = 'c', id = "config_path", value_name = "PATH")]
     pub config: Option<std::path::PathBuf>,
 
     /// Output validation results in JSON format
     #[arg(long)]
     pub json: bool,
 
-    /// Attempt to fix auto-fixable issues
+    /// Pretty-print JSON output (default: compact, one line)
+    #[arg(long, requires = "json")]
+    pub pretty: bool,
+
+    /// Treat warnings as errors (also enabled by `validation.warnings_as_errors`)
     #[arg(long)]
-    pub fix: bool,
+    pub strict: bool,
 }
 
 /// Migrate command arguments.
 #[derive(Parser, Clone, Debug)]
 pub struct MigrateArgs {
-    /// Config file path (defaults to ~/.config/pdb-sync/config.toml)
-    #[arg(short, long)]
+    /// Config file path (defaults to ~/.config/pdb-sync/config.toml; `--config` also works)
+    #[arg(short = 'c', id = "config_path", value_name = "PATH")]
     pub config: Option<std::path::PathBuf>,
 
     /// Dry run - show what would be changed without modifying the file
-    #[arg(short = 'n', long)]
+    /// (set by the global `--dry-run`)
+    #[arg(skip)]
     pub dry_run: bool,
 }
 
 /// Init command arguments.
 #[derive(Parser, Clone, Debug)]
 pub struct InitArgs {
-    /// Config file path (defaults to ~/.config/pdb-sync/config.toml)
-    #[arg(short, long)]
+    /// Config file path (defaults to ~/.config/pdb-sync/config.toml; `--config` also works)
+    #[arg(short = 'c', id = "config_path", value_name = "PATH")]
     pub config: Option<std::path::PathBuf>,
 
     /// Overwrite existing config file
@@ -70,39 +75,22 @@ pub struct InitArgs {
     /// PDB directory path to use in the config
     #[arg(long)]
     pub pdb_dir: Option<std::path::PathBuf>,
+
+    /// Dry run - show the config that would be written (set by the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
 }
 
 /// Run config validate command.
 pub async fn run_validate(args: ValidateArgs) -> crate::error::Result<()> {
-    // If config path is provided, use the new validation
-    if args.config.is_some() || !args.fix {
-        use crate::cli::commands::config::ConfigCommand;
-        let cmd = ConfigCommand::Validate {
-            config_path: args.config,
-        };
-        return crate::cli::commands::config::run_config(cmd).await;
-    }
-
-    // Otherwise, use the original validation with fix support
-    use crate::config::ConfigLoader;
-    use crate::sync::validator::validate_config;
-
-    println!("Validating configuration...");
-
-    let config = ConfigLoader::load()?;
-    let validation = validate_config(&config);
-
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&validation)?);
-    } else {
-        validation.print();
-    }
-
-    if validation.has_errors() {
-        std::process::exit(1);
-    }
-
-    Ok(())
+    use crate::cli::commands::config::ConfigCommand;
+    let cmd = ConfigCommand::Validate {
+        config_path: args.config,
+        strict: args.strict,
+        json: args.json,
+        pretty: args.pretty,
+    };
+    crate::cli::commands::config::run_config(cmd).await
 }
 
 /// Run config migrate command.
@@ -137,6 +125,7 @@ pub async fn run_init(args: InitArgs) -> crate::error::Result<()> {
         force: args.force,
         minimal: args.minimal,
         pdb_dir: args.pdb_dir,
+        dry_run: args.dry_run,
     };
     crate::cli::commands::config::run_config(cmd).await
 }
@@ -3,6 +3,8 @@
 use crate::context::AppContext;
 use crate::error::Result;
 use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+use pdb_sync::DataType;
 
 /// Environment command arguments.
 #[derive(Parser, Clone, Debug)]
@@ -15,12 +17,24 @@ pub struct EnvArgs {
 #[derive(Subcommand, Clone, Debug)]
 pub enum EnvCommand {
     /// Run environment diagnostics
-    Doctor,
+    Doctor {
+        /// Also check the directory this data type syncs into (repeatable)
+        #[arg(long = "data-type", value_name = "TYPE")]
+        data_types: Vec<DataType>,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for (detected from $SHELL if omitted)
+        shell: Option<Shell>,
+    },
 }
 
 /// Run env based on arguments.
 pub fn run_env(args: EnvArgs, ctx: AppContext) -> Result<()> {
     match args.command {
-        EnvCommand::Doctor => crate::cli::commands::env::run_doctor(ctx),
+        EnvCommand::Doctor { data_types } => {
+            crate::cli::commands::env::run_doctor(ctx, &data_types)
+        }
+        EnvCommand::Completions { shell } => crate::cli::commands::env::run_completions(shell),
     }
 }
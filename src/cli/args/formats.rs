@@ -0,0 +1,26 @@
+//! Formats command arguments.
+
+use crate::error::Result;
+use crate::mirrors::MirrorId;
+use clap::Parser;
+
+/// Formats command arguments.
+#[derive(Parser, Clone, Debug)]
+pub struct FormatsArgs {
+    /// Only list formats served by this mirror
+    #[arg(long, value_name = "MIRROR")]
+    pub mirror: Option<MirrorId>,
+
+    /// Output the format list in JSON format
+    #[arg(long)]
+    pub json: bool,
+
+    /// Pretty-print JSON output (default: compact, one line)
+    #[arg(long, requires = "json")]
+    pub pretty: bool,
+}
+
+/// Run formats based on arguments.
+pub fn run_formats(args: FormatsArgs) -> Result<()> {
+    crate::cli::commands::formats::run_formats(args.mirror, args.json, args.pretty)
+}
@@ -7,6 +7,8 @@ use std::path::PathBuf;
 
 use super::config::ConfigArgs;
 use super::env::EnvArgs;
+use super::formats::FormatsArgs;
+use super::presets::PresetsArgs;
 use super::sync::SyncArgs;
 
 // Configures colored help menu colors (similar to uv)
@@ -30,6 +32,18 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub pdb_dir: Option<PathBuf>,
 
+    /// Assume yes for confirmation prompts (e.g., creating the PDB directory)
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
+    /// Show what would be done without changing any files (rsync runs, config writes)
+    #[arg(short = 'n', long, global = true)]
+    pub dry_run: bool,
+
+    /// Load configuration from this file instead of the default location [env: PDB_SYNC_CONFIG]
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: SyncCommand,
 }
@@ -43,11 +57,84 @@ pub enum SyncCommand {
     Env(EnvArgs),
     /// Configuration management
     Config(ConfigArgs),
+    /// List supported file formats
+    Formats(FormatsArgs),
+    /// List and inspect profile and rsync flag presets
+    Presets(PresetsArgs),
+}
+
+/// Build the clap command with colored styles
+pub fn cli_command() -> clap::Command {
+    Cli::command().styles(STYLES).color(clap::ColorChoice::Auto)
+}
+
+impl SyncCommand {
+    /// Whether this command creates or checks the config file itself.
+    ///
+    /// `config init`, `validate` and `migrate` take the config path as their
+    /// target, so it must not be loaded (or required to exist) up front.
+    pub fn manages_config_file(&self) -> bool {
+        use super::config::ConfigCommand;
+
+        matches!(
+            self,
+            SyncCommand::Config(args) if matches!(
+                args.command,
+                ConfigCommand::Init(_) | ConfigCommand::Validate(_) | ConfigCommand::Migrate(_)
+            )
+        )
+    }
 }
 
 /// Parse CLI with colored styles
 pub fn parse_cli() -> Cli {
-    let cmd = Cli::command().styles(STYLES).color(clap::ColorChoice::Auto);
-    let matches = cmd.get_matches();
+    let matches = cli_command().get_matches();
     Cli::from_arg_matches(&matches).expect("Failed to parse arguments")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_dry_run_before_or_after_subcommand() {
+        for argv in [
+            vec!["pdb-sync", "--dry-run", "sync", "structures"],
+            vec!["pdb-sync", "sync", "structures", "-n"],
+            vec!["pdb-sync", "config", "migrate", "--dry-run"],
+            vec!["pdb-sync", "-n", "config", "init"],
+        ] {
+            let cli = Cli::try_parse_from(&argv).unwrap();
+            assert!(cli.dry_run, "{:?}", argv);
+        }
+
+        let cli = Cli::try_parse_from(["pdb-sync", "sync"]).unwrap();
+        assert!(!cli.dry_run);
+    }
+
+    #[test]
+    fn test_global_config_before_or_after_subcommand() {
+        for argv in [
+            vec!["pdb-sync", "--config", "x.toml", "sync"],
+            vec!["pdb-sync", "sync", "--config", "x.toml"],
+            vec!["pdb-sync", "env", "doctor", "--config", "x.toml"],
+        ] {
+            let cli = Cli::try_parse_from(&argv).unwrap();
+            assert_eq!(cli.config, Some(PathBuf::from("x.toml")), "{:?}", argv);
+        }
+
+        // `-c` still selects the target file of config subcommands
+        let cli = Cli::try_parse_from(["pdb-sync", "config", "validate", "-c", "y.toml"]).unwrap();
+        assert!(cli.config.is_none());
+        assert!(cli.command.manages_config_file());
+        match cli.command {
+            SyncCommand::Config(args) => match args.command {
+                super::super::config::ConfigCommand::Validate(v) => {
+                    assert_eq!(v.config, Some(PathBuf::from("y.toml")))
+                }
+                _ => panic!("expected validate"),
+            },
+            _ => panic!("expected config"),
+        }
+    }
+}
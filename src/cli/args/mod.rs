@@ -5,15 +5,21 @@
 //! - [`global`]: Global CLI structures and STYLES constant
 //! - [`sync`]: Sync command arguments
 //! - [`config`]: Config command arguments
+//! - [`formats`]: Formats command arguments
+//! - [`parsers`]: Custom value parsers and validators
+//! - [`presets`]: Presets command arguments
 
 pub mod config;
 mod enums;
 pub mod env;
+pub mod formats;
 mod global;
+pub mod parsers;
+pub mod presets;
 pub mod sync;
 
 // Re-export global CLI types (for external use by main.rs)
-pub use global::{parse_cli, SyncCommand};
+pub use global::{cli_command, parse_cli, SyncCommand};
 
 // Sync-related arguments
 pub use sync::SyncArgs;
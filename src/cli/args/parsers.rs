@@ -18,6 +18,7 @@
 /// assert!(validate_resolution("inf").is_err());
 /// assert!(validate_resolution("NaN").is_err());
 /// ```
+#[allow(dead_code)]
 pub fn validate_resolution(s: &str) -> Result<f64, String> {
     let value: f64 = s.parse().map_err(|_| format!("Invalid number: {}", s))?;
 
@@ -50,6 +51,7 @@ pub fn validate_resolution(s: &str) -> Result<f64, String> {
 /// assert!(validate_organism("test@invalid").is_err());
 /// assert!(validate_organism("").is_err());
 /// ```
+#[allow(dead_code)]
 pub fn validate_organism(s: &str) -> Result<String, String> {
     const MAX_LEN: usize = 200;
 
@@ -127,6 +129,81 @@ pub fn validate_interval(s: &str) -> Result<String, String> {
     }
 }
 
+/// Parse an interval string (e.g., "30s", "5m") into a [`Duration`].
+///
+/// Accepts the same formats as [`validate_interval`].
+///
+/// [`Duration`]: std::time::Duration
+pub fn parse_interval(s: &str) -> Result<std::time::Duration, String> {
+    validate_interval(s)?;
+
+    let (num_part, unit) = s.split_at(s.len() - 1);
+    let num: u64 = num_part
+        .parse()
+        .map_err(|_| format!("Invalid interval number: {}", num_part))?;
+
+    let secs = match unit {
+        "s" => Some(num),
+        "m" => num.checked_mul(60),
+        "h" => num.checked_mul(60 * 60),
+        "d" => num.checked_mul(24 * 60 * 60),
+        _ => None,
+    }
+    .ok_or_else(|| format!("Interval too large: {}", s))?;
+
+    Ok(std::time::Duration::from_secs(secs))
+}
+
+/// Parse a bandwidth limit (e.g., "500", "500K", "10M", "1.5G") into KiB/s.
+///
+/// A bare number is KiB/s, as with rsync's `--bwlimit`. The suffixes K, M
+/// and G (optionally followed by "iB") are powers of 1024. Non-zero limits
+/// below 1 KiB/s become 1, since rsync reads 0 as "no limit".
+///
+/// # Examples
+///
+/// ```text
+/// "500"   -> 500
+/// "10M"   -> 10240
+/// "1.5G"  -> 1572864
+/// "0.4K"  -> 1
+/// "fast"  -> error
+/// ```
+pub fn parse_bwlimit(s: &str) -> Result<u32, String> {
+    const USAGE: &str = "expected a number with an optional K, M or G suffix (e.g., 500K, 10M)";
+
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (num_part, suffix) = s.split_at(split);
+
+    let num: f64 = num_part
+        .parse()
+        .map_err(|_| format!("Invalid bandwidth limit '{}': {}", s, USAGE))?;
+
+    let multiplier = match suffix.to_ascii_uppercase().trim_end_matches("IB") {
+        "" | "K" => 1.0,
+        "M" => 1024.0,
+        "G" => 1024.0 * 1024.0,
+        _ => {
+            return Err(format!(
+                "Invalid bandwidth unit '{}' in '{}': {}",
+                suffix, s, USAGE
+            ))
+        }
+    };
+
+    let mut kib = (num * multiplier).round();
+    if kib == 0.0 && num > 0.0 {
+        kib = 1.0;
+    }
+    if kib > f64::from(u32::MAX) {
+        return Err(format!("Bandwidth limit too large: {}", s));
+    }
+    Ok(kib as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +271,45 @@ mod tests {
         assert!(validate_interval("0h").is_err());
         assert!(validate_interval("0d").is_err());
     }
+
+    #[test]
+    fn test_parse_bwlimit() {
+        assert_eq!(parse_bwlimit("0").unwrap(), 0);
+        assert_eq!(parse_bwlimit("500").unwrap(), 500);
+        assert_eq!(parse_bwlimit("500K").unwrap(), 500);
+        assert_eq!(parse_bwlimit("500k").unwrap(), 500);
+        assert_eq!(parse_bwlimit("10M").unwrap(), 10 * 1024);
+        assert_eq!(parse_bwlimit("10MiB").unwrap(), 10 * 1024);
+        assert_eq!(parse_bwlimit("1.5G").unwrap(), 1536 * 1024);
+        assert_eq!(parse_bwlimit("0.5K").unwrap(), 1); // rounded
+        assert_eq!(parse_bwlimit("1.4").unwrap(), 1);
+        // Tiny limits must not round down to rsync's "unlimited"
+        assert_eq!(parse_bwlimit("0.4K").unwrap(), 1);
+        assert_eq!(parse_bwlimit("0.001").unwrap(), 1);
+
+        assert!(parse_bwlimit("").is_err());
+        assert!(parse_bwlimit("fast").is_err());
+        assert!(parse_bwlimit("10X").is_err());
+        assert!(parse_bwlimit("10MB").is_err()); // ambiguous decimal unit
+        assert!(parse_bwlimit("-5M").is_err());
+        assert!(parse_bwlimit("1.2.3M").is_err());
+        assert!(parse_bwlimit("99999G").is_err());
+
+        let err = parse_bwlimit("10X").unwrap_err();
+        assert!(err.contains("500K"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        use std::time::Duration;
+
+        assert_eq!(parse_interval("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_interval("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_interval("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_interval("1d").unwrap(), Duration::from_secs(86400));
+
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("10").is_err());
+        assert!(parse_interval("99999999999999999d").is_err());
+    }
 }
@@ -0,0 +1,50 @@
+//! Presets command arguments.
+
+use crate::error::Result;
+use clap::{Parser, Subcommand};
+
+/// Presets command arguments.
+#[derive(Parser, Clone, Debug)]
+pub struct PresetsArgs {
+    #[command(subcommand)]
+    pub command: PresetsCommand,
+}
+
+/// Presets subcommands.
+#[derive(Subcommand, Clone, Debug)]
+pub enum PresetsCommand {
+    /// List profile presets and rsync flag presets
+    List {
+        /// Output presets in JSON format
+        #[arg(long)]
+        json: bool,
+
+        /// Pretty-print JSON output (default: compact, one line)
+        #[arg(long, requires = "json")]
+        pretty: bool,
+    },
+    /// Show what a single preset expands to
+    Show {
+        /// Preset name (profile or rsync flag preset)
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        /// Output the preset in JSON format
+        #[arg(long)]
+        json: bool,
+
+        /// Pretty-print JSON output (default: compact, one line)
+        #[arg(long, requires = "json")]
+        pretty: bool,
+    },
+}
+
+/// Run presets based on arguments.
+pub fn run_presets(args: PresetsArgs) -> Result<()> {
+    use crate::cli::commands::presets;
+
+    match args.command {
+        PresetsCommand::List { json, pretty } => presets::run_list(json, pretty),
+        PresetsCommand::Show { name, json, pretty } => presets::run_show(&name, json, pretty),
+    }
+}
@@ -28,8 +28,8 @@ pub struct SyncArgs {
     #[arg(long)]
     pub fail_fast: bool,
 
-    /// Dry run - show the rsync command without executing
-    #[arg(short = 'n', long)]
+    /// Dry run - show the rsync command without executing (set by the global `--dry-run`)
+    #[arg(skip)]
     pub dry_run: bool,
 
     /// Delete files not present on remote
@@ -76,6 +76,10 @@ pub struct SyncArgs {
     #[arg(long)]
     pub modify_window: Option<u32>,
 
+    /// Detect the destination filesystem's timestamp granularity and set --modify-window to match
+    #[arg(long)]
+    pub auto_modify_window: bool,
+
     /// Keep partially transferred files
     #[arg(long)]
     pub partial: bool,
@@ -136,8 +140,8 @@ pub struct SyncArgs {
     #[arg(long)]
     pub include_from: Option<String>,
 
-    /// Bandwidth limit in KB/s
-    #[arg(long)]
+    /// Bandwidth limit in KiB/s, or with a K/M/G suffix (e.g., 500K, 10M, 1.5G)
+    #[arg(long, value_name = "RATE", value_parser = super::parsers::parse_bwlimit)]
     pub bwlimit: Option<u32>,
 
     /// Verbose rsync output
@@ -168,6 +172,10 @@ pub struct SyncArgs {
     #[arg(long)]
     pub plan: bool,
 
+    /// Preview mode - summarize files to be created, updated, and deleted
+    #[arg(long, conflicts_with = "plan")]
+    pub preview: bool,
+
     /// List available profile presets
     #[arg(long)]
     pub profile_list: bool,
@@ -191,6 +199,41 @@ pub struct SyncArgs {
     /// Delay between retries in seconds (default: exponential backoff)
     #[arg(long, value_name = "SECONDS")]
     pub retry_delay: Option<u32>,
+
+    /// Only sync files modified at or before this date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+    #[arg(long, value_name = "DATE", value_parser = crate::sync::listing::parse_cutoff)]
+    pub exclude_newer_than: Option<chrono::NaiveDateTime>,
+
+    /// Print aggregate progress lines at most once per interval (e.g., 30s, 5m) instead of a live bar
+    #[arg(long, value_name = "INTERVAL", value_parser = super::parsers::parse_interval)]
+    pub progress_interval: Option<std::time::Duration>,
+
+    /// Write parsed rsync statistics as JSON lines to stdout or append them to PATH
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "-"
+    )]
+    pub stats_json: Option<std::path::PathBuf>,
+
+    /// Sync each top-level remote directory with its own rsync process
+    ///
+    /// With --delete, top-level directories removed on the remote are kept locally.
+    #[arg(
+        long,
+        conflicts_with_all = ["exclude_newer_than", "only_missing", "plan", "preview"]
+    )]
+    pub split_dirs: bool,
+
+    /// Only transfer files that don't exist in the destination yet
+    #[arg(long, conflicts_with = "delete")]
+    pub only_missing: bool,
+
+    /// Maximum number of concurrent rsync processes with --split-dirs
+    #[arg(long, value_name = "N", default_value = "4", requires = "split_dirs")]
+    pub max_concurrent_dirs: usize,
 }
 
 impl SyncArgs {
@@ -365,7 +408,7 @@ pub async fn run_sync(args: SyncArgs, ctx: AppContext) -> Result<()> {
             ))
         })?;
 
-        if args.profile_dry_run {
+        if args.profile_dry_run || args.dry_run {
             println!("Dry-run mode - would add the following profile to config:");
             println!();
             println!("Name: {}", preset.name);
@@ -587,6 +630,7 @@ mod tests {
             ignore_times: false,
             no_ignore_times: false,
             modify_window: None,
+            auto_modify_window: false,
             partial: false,
             no_partial: false,
             partial_dir: None,
@@ -610,11 +654,18 @@ mod tests {
             itemize_changes: false,
             no_itemize_changes: false,
             plan: false,
+            preview: false,
             profile_list: false,
             profile_add: None,
             profile_dry_run: false,
             parallel: None,
             retry_delay: None,
+            exclude_newer_than: None,
+            progress_interval: None,
+            stats_json: None,
+            split_dirs: false,
+            only_missing: false,
+            max_concurrent_dirs: 4,
         };
         assert!(args.validate().is_err());
     }
@@ -640,6 +691,7 @@ mod tests {
             ignore_times: false,
             no_ignore_times: false,
             modify_window: None,
+            auto_modify_window: false,
             partial: false,
             no_partial: false,
             partial_dir: None,
@@ -663,11 +715,18 @@ mod tests {
             itemize_changes: false,
             no_itemize_changes: false,
             plan: false,
+            preview: false,
             profile_list: false,
             profile_add: None,
             profile_dry_run: false,
             parallel: None,
             retry_delay: None,
+            exclude_newer_than: None,
+            progress_interval: None,
+            stats_json: None,
+            split_dirs: false,
+            only_missing: false,
+            max_concurrent_dirs: 4,
         };
         assert!(args.validate().is_ok());
     }
@@ -693,6 +752,7 @@ mod tests {
             ignore_times: false,
             no_ignore_times: false,
             modify_window: None,
+            auto_modify_window: false,
             partial: false,
             no_partial: false,
             partial_dir: None,
@@ -716,12 +776,34 @@ mod tests {
             itemize_changes: false,
             no_itemize_changes: false,
             plan: false,
+            preview: false,
             profile_list: false,
             profile_add: None,
             profile_dry_run: false,
             parallel: None,
             retry_delay: None,
+            exclude_newer_than: None,
+            progress_interval: None,
+            stats_json: None,
+            split_dirs: false,
+            only_missing: false,
+            max_concurrent_dirs: 4,
         };
         assert!(args.validate().is_ok());
     }
+
+    #[test]
+    fn test_split_dirs_conflicts_with_plan_and_preview() {
+        #[derive(Parser)]
+        struct TestCli {
+            #[command(flatten)]
+            sync: SyncArgs,
+        }
+
+        for flag in ["--plan", "--preview"] {
+            let argv = ["sync", "structures", "--split-dirs", flag];
+            assert!(TestCli::try_parse_from(argv).is_err(), "{:?}", argv);
+        }
+        assert!(TestCli::try_parse_from(["sync", "structures", "--split-dirs"]).is_ok());
+    }
 }
@@ -54,9 +54,14 @@
 //! pdb-sync config list
 //! ```
 
+use crate::config::loader::ConfigLayer;
 use crate::config::schema::{Config, CustomRsyncConfig, RsyncOptionsConfig};
+use crate::config::ConfigLoader;
 use crate::error::{PdbSyncError, Result};
+use crate::sync::validator::{validate_layers, ValidationStatus};
 use crate::sync::{list_rsync_presets, RsyncFlags, RsyncPreset};
+use serde::Serialize;
+use std::io::Write;
 use std::path::PathBuf;
 
 /// Result of a migration attempt.
@@ -83,6 +88,8 @@ pub enum ConfigCommand {
         minimal: bool,
         /// PDB directory path
         pdb_dir: Option<PathBuf>,
+        /// Dry run - show the config without writing it
+        dry_run: bool,
     },
     /// Migrate old config format to new nested format
     Migrate {
@@ -95,6 +102,12 @@ pub enum ConfigCommand {
     Validate {
         /// Config file path (defaults to ~/.config/pdb-sync/config.toml)
         config_path: Option<PathBuf>,
+        /// Treat warnings as errors
+        strict: bool,
+        /// Output validation results as JSON
+        json: bool,
+        /// Pretty-print JSON output
+        pretty: bool,
     },
     /// List available rsync flag presets
     Presets,
@@ -110,12 +123,25 @@ pub async fn run_config(cmd: ConfigCommand) -> Result<()> {
             force,
             minimal,
             pdb_dir,
-        } => run_init(config_path, force, minimal, pdb_dir).await,
+            dry_run,
+        } => run_init(config_path, force, minimal, pdb_dir, dry_run).await,
         ConfigCommand::Migrate {
             config_path,
             dry_run,
         } => run_migrate(config_path, dry_run).await,
-        ConfigCommand::Validate { config_path } => run_validate(config_path).await,
+        ConfigCommand::Validate {
+            config_path,
+            strict,
+            json,
+            pretty,
+        } => {
+            let options = ValidateOptions {
+                strict,
+                json,
+                pretty,
+            };
+            run_validate(config_path, options, &mut std::io::stdout()).await
+        }
         ConfigCommand::Presets => run_presets().await,
         ConfigCommand::List => run_list().await,
     }
@@ -127,6 +153,7 @@ async fn run_init(
     force: bool,
     minimal: bool,
     pdb_dir: Option<PathBuf>,
+    dry_run: bool,
 ) -> Result<()> {
     let config_path = config_path.unwrap_or_else(|| {
         crate::config::ConfigLoader::config_path().unwrap_or_else(|| PathBuf::from("config.toml"))
@@ -144,6 +171,30 @@ async fn run_init(
         });
     }
 
+    // Generate config content
+    let pdb_dir_provided = pdb_dir.is_some();
+    let content = if minimal {
+        generate_minimal_config(pdb_dir)
+    } else {
+        generate_full_config(pdb_dir)
+    };
+
+    if dry_run {
+        println!(
+            "Dry run - would write config file: {}",
+            config_path.display()
+        );
+        if config_path.exists() {
+            println!(
+                "  (existing file would be backed up to {}.bak)",
+                config_path.display()
+            );
+        }
+        println!();
+        println!("{}", content);
+        return Ok(());
+    }
+
     // Create parent directories if needed
     if let Some(parent) = config_path.parent() {
         if !parent.exists() {
@@ -171,14 +222,6 @@ async fn run_init(
         println!("Created backup at: {}", backup_path.display());
     }
 
-    // Generate config content
-    let pdb_dir_provided = pdb_dir.is_some();
-    let content = if minimal {
-        generate_minimal_config(pdb_dir)
-    } else {
-        generate_full_config(pdb_dir)
-    };
-
     // Write config file
     tokio::fs::write(&config_path, &content)
         .await
@@ -286,6 +329,10 @@ preset = "safe"
 # [sync.custom.emdb.options]
 # max_size = "5G"
 
+# Fail `config validate` on warnings as well as errors (same as --strict)
+# [validation]
+# warnings_as_errors = true
+
 # SIFTS (Structure Integration with Function, Taxonomy and Sequence)
 # [sync.custom.sifts]
 # url = "rsync.wwpdb.org::ftp/pdb/data/structures/divided/XML/"
@@ -419,30 +466,31 @@ fn try_migrate_custom_config(name: &str, custom: &mut CustomRsyncConfig) -> Migr
         }
     }
 
-    // If no preset matches, convert to nested options format
+    // If no preset matches, convert to nested options format,
+    // keeping only the flags that differ from the defaults
     custom.options = Some(RsyncOptionsConfig {
-        delete: Some(custom.rsync_delete),
-        compress: Some(custom.rsync_compress),
-        checksum: Some(custom.rsync_checksum),
-        size_only: Some(custom.rsync_size_only),
-        ignore_times: Some(custom.rsync_ignore_times),
+        delete: custom.rsync_delete.then_some(true),
+        compress: custom.rsync_compress.then_some(true),
+        checksum: custom.rsync_checksum.then_some(true),
+        size_only: custom.rsync_size_only.then_some(true),
+        ignore_times: custom.rsync_ignore_times.then_some(true),
         modify_window: custom.rsync_modify_window,
-        partial: Some(custom.rsync_partial),
+        partial: custom.rsync_partial.then_some(true),
         partial_dir: custom.rsync_partial_dir.clone(),
         max_size: custom.rsync_max_size.clone(),
         min_size: custom.rsync_min_size.clone(),
         timeout: custom.rsync_timeout,
         contimeout: custom.rsync_contimeout,
-        backup: Some(custom.rsync_backup),
+        backup: custom.rsync_backup.then_some(true),
         backup_dir: custom.rsync_backup_dir.clone(),
         chmod: custom.rsync_chmod.clone(),
         exclude: custom.rsync_exclude.clone(),
         include: custom.rsync_include.clone(),
         exclude_from: custom.rsync_exclude_from.clone(),
         include_from: custom.rsync_include_from.clone(),
-        verbose: Some(custom.rsync_verbose),
-        quiet: Some(custom.rsync_quiet),
-        itemize_changes: Some(custom.rsync_itemize_changes),
+        verbose: custom.rsync_verbose.then_some(true),
+        quiet: custom.rsync_quiet.then_some(true),
+        itemize_changes: custom.rsync_itemize_changes.then_some(true),
     });
 
     clear_legacy_fields(custom);
@@ -502,58 +550,174 @@ fn clear_legacy_fields(custom: &mut CustomRsyncConfig) {
     custom.rsync_itemize_changes = false;
 }
 
-/// Validate config file syntax.
-async fn run_validate(config_path: Option<PathBuf>) -> Result<()> {
-    let config_path = config_path.unwrap_or_else(|| {
-        crate::config::ConfigLoader::config_path().unwrap_or_else(|| PathBuf::from("config.toml"))
-    });
+/// Config files checked by `config validate`, lowest precedence first.
+///
+/// An explicit path is validated on its own. Otherwise the global config and
+/// the project-local config are validated; if neither exists, the default
+/// global path is reported as missing.
+fn validate_paths(config_path: Option<PathBuf>) -> Vec<PathBuf> {
+    if let Some(path) = config_path {
+        return vec![path];
+    }
 
-    println!("Validating config: {}", config_path.display());
+    let global = ConfigLoader::config_path();
+    let mut paths: Vec<_> = global.iter().filter(|p| p.exists()).cloned().collect();
+    paths.extend(ConfigLoader::project_config_path());
+    if paths.is_empty() {
+        paths.push(global.unwrap_or_else(|| PathBuf::from("config.toml")));
+    }
+    paths
+}
 
-    // Load and parse config
-    let content =
-        tokio::fs::read_to_string(&config_path)
+/// Flags of `config validate`.
+#[derive(Debug, Clone, Copy, Default)]
+struct ValidateOptions {
+    strict: bool,
+    json: bool,
+    pretty: bool,
+}
+
+/// JSON report for configs that could not be loaded for validation.
+#[derive(Debug, Serialize)]
+struct LoadFailure {
+    valid: bool,
+    errors: Vec<String>,
+}
+
+/// Validate config file syntax, writing the report to `out`.
+///
+/// With `json`, only the serialized `ValidationResult` is written. Configs
+/// that can't be read, parsed or turned into rsync flags are reported as
+/// `{"valid": false, "errors": [...]}` before the error is returned.
+async fn run_validate<W: Write>(
+    config_path: Option<PathBuf>,
+    options: ValidateOptions,
+    out: &mut W,
+) -> Result<()> {
+    let ValidateOptions {
+        strict,
+        json,
+        pretty,
+    } = options;
+    let paths = validate_paths(config_path);
+    if !json {
+        for path in &paths {
+            writeln!(out, "Validating config: {}", path.display())?;
+        }
+    }
+
+    let layers = match load_validate_layers(paths).await {
+        Ok(layers) => layers,
+        Err(e) => {
+            if json {
+                let failure = LoadFailure {
+                    valid: false,
+                    errors: vec![e.to_string()],
+                };
+                writeln!(out, "{}", pdb_sync::utils::to_json(&failure, pretty)?)?;
+            }
+            return Err(e);
+        }
+    };
+    let merged = ConfigLoader::merge_layers(&layers);
+
+    // Report problems found by the config validator
+    let validation = validate_layers(&layers);
+    if json {
+        writeln!(out, "{}", pdb_sync::utils::to_json(&validation, pretty)?)?;
+    } else {
+        for check in &validation.checks {
+            let marker = match check.status {
+                ValidationStatus::Pass => continue,
+                ValidationStatus::Warning => "⚠",
+                ValidationStatus::Error => "✗",
+            };
+            match check.file {
+                Some(ref file) => writeln!(
+                    out,
+                    "{} {}: {}: {}",
+                    marker,
+                    file.display(),
+                    check.name,
+                    check.message
+                )?,
+                None => writeln!(out, "{} {}: {}", marker, check.name, check.message)?,
+            }
+        }
+    }
+
+    let strict = strict || merged.validation.strict();
+    if !validation.passes(strict) {
+        let message = if validation.has_errors() {
+            "Config has validation errors"
+        } else {
+            "Config has warnings (treated as errors in strict mode)"
+        };
+        return Err(PdbSyncError::Validation(message.to_string()));
+    }
+
+    if !json {
+        writeln!(out, "✓ Config is valid")?;
+        writeln!(out, "  {} custom rsync configs", merged.sync.custom.len())?;
+    }
+
+    Ok(())
+}
+
+/// Read and parse each config file, checking that its presets exist and that
+/// every custom rsync config yields valid flags.
+async fn load_validate_layers(paths: Vec<PathBuf>) -> Result<Vec<ConfigLayer>> {
+    let mut layers = Vec::new();
+    for path in paths {
+        // Load and parse config
+        let content = tokio::fs::read_to_string(&path)
             .await
             .map_err(|e| PdbSyncError::Config {
-                message: format!("Failed to read config file: {}", e),
+                message: format!("Failed to read config file {}: {}", path.display(), e),
                 key: None,
                 source: Some(Box::new(e)),
             })?;
 
-    let config: Config = toml::from_str(&content).map_err(|e| PdbSyncError::Config {
-        message: format!("Failed to parse config: {}", e),
-        key: None,
-        source: Some(Box::new(e)),
-    })?;
-
-    // Validate each custom rsync config
-    for (name, custom) in &config.sync.custom {
-        let flags: RsyncFlags = custom.to_rsync_flags(config.sync.defaults.as_ref());
-        flags.validate().map_err(|e| PdbSyncError::Config {
-            message: format!("Invalid config for '{}': {}", name, e),
-            key: Some(name.clone()),
-            source: None,
+        let config: Config = toml::from_str(&content).map_err(|e| PdbSyncError::Config {
+            message: format!("Failed to parse config {}: {}", path.display(), e),
+            key: None,
+            source: Some(Box::new(e)),
         })?;
 
-        // Validate preset name if specified
-        if let Some(ref preset_name) = custom.preset {
-            if crate::sync::get_rsync_preset(preset_name).is_none() {
-                return Err(PdbSyncError::Config {
-                    message: format!(
-                        "Invalid preset '{}' for '{}'. Valid presets: safe, fast, minimal, conservative",
-                        preset_name, name
-                    ),
-                    key: Some(format!("sync.custom.{}.preset", name)),
-                    source: None,
-                });
+        // Validate preset names of custom rsync configs
+        for (name, custom) in &config.sync.custom {
+            if let Some(ref preset_name) = custom.preset {
+                if crate::sync::get_rsync_preset(preset_name).is_none() {
+                    return Err(PdbSyncError::Validation(format!(
+                        "{}: invalid preset '{}' for '{}'. Valid presets: safe, fast, minimal, conservative",
+                        path.display(),
+                        preset_name,
+                        name
+                    )));
+                }
             }
         }
+
+        layers.push(ConfigLayer { path, config });
     }
 
-    println!("✓ Config is valid");
-    println!("  {} custom rsync configs", config.sync.custom.len());
+    // Validate the flags each custom rsync config ends up with
+    let merged = ConfigLoader::merge_layers(&layers);
+    for layer in &layers {
+        for (name, custom) in &layer.config.sync.custom {
+            let flags: RsyncFlags = custom.to_rsync_flags(merged.sync.defaults.as_ref());
+            flags.validate().map_err(|e| {
+                PdbSyncError::Validation(format!(
+                    "{}: invalid config for '{}': {}",
+                    layer.path.display(),
+                    name,
+                    e
+                ))
+            })?;
+        }
+    }
 
-    Ok(())
+    Ok(layers)
 }
 
 /// List available rsync flag presets.
@@ -566,40 +730,38 @@ async fn run_presets() -> Result<()> {
 async fn run_list() -> Result<()> {
     use crate::config::ConfigLoader;
 
-    let config_path = ConfigLoader::config_path();
-
-    let config = match &config_path {
-        Some(path) if path.exists() => {
-            println!("Config: {}", path.display());
-            let content = std::fs::read_to_string(path).map_err(|e| PdbSyncError::Config {
-                message: format!("Failed to read config file: {}", e),
-                key: None,
-                source: Some(Box::new(e)),
-            })?;
-            toml::from_str::<Config>(&content).map_err(|e| PdbSyncError::Config {
-                message: format!("Failed to parse config {}: {}", path.display(), e),
-                key: None,
-                source: Some(Box::new(e)),
-            })?
-        }
-        Some(path) => {
-            eprintln!("Warning: Config file not found: {}", path.display());
-            eprintln!("  Using default configuration.");
-            Config::default()
-        }
-        None => {
-            println!("Config: (no config file found)");
-            Config::default()
+    let layers = ConfigLoader::load_layers()?;
+    if layers.is_empty() {
+        match ConfigLoader::config_path() {
+            Some(path) => {
+                eprintln!("Warning: Config file not found: {}", path.display());
+                eprintln!("  Using default configuration.");
+            }
+            None => println!("Config: (no config file found)"),
         }
-    };
+    }
+    for layer in &layers {
+        println!("Config: {}", layer.path.display());
+    }
+
+    let pdb_dir = crate::context::resolve_pdb_dir(&layers);
+    println!(
+        "PDB directory: {} (from {})",
+        pdb_dir.value.display(),
+        pdb_dir.origin()
+    );
     println!();
 
-    print_sync_targets(&config);
+    let config = ConfigLoader::merge_layers(&layers);
+    print_sync_targets(&config, &layers);
     Ok(())
 }
 
 /// Print sync targets from a config.
-fn print_sync_targets(config: &Config) {
+///
+/// When more than one config file is loaded, each target is annotated with
+/// the file that defines it.
+fn print_sync_targets(config: &Config, layers: &[crate::config::loader::ConfigLayer]) {
     if config.sync.custom.is_empty() {
         println!("No sync targets configured.");
         return;
@@ -631,20 +793,256 @@ fn print_sync_targets(config: &Config) {
         if let Some(ref desc) = custom.description {
             println!("  {:<width$}  {}", "", desc, width = max_name_len,);
         }
+        if layers.len() > 1 {
+            if let Some(layer) = layers
+                .iter()
+                .rev()
+                .find(|l| l.config.sync.custom.contains_key(*name))
+            {
+                println!(
+                    "  {:<width$}  (from {})",
+                    "",
+                    layer.path.display(),
+                    width = max_name_len,
+                );
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sync::validator::ValidationResult;
     use tempfile::TempDir;
 
+    const LEGACY_CONFIG: &str = r#"
+[sync.custom.structures]
+url = "rsync.wwpdb.org::ftp_data/structures/divided/mmCIF/"
+dest = "data/structures/divided/mmCIF"
+rsync_delete = true
+rsync_compress = false
+rsync_timeout = 300
+rsync_exclude = ["*.tmp"]
+"#;
+
+    #[tokio::test]
+    async fn test_migrate_emits_only_non_default_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        run_migrate(Some(config_path.clone()), false).await.unwrap();
+
+        let migrated = std::fs::read_to_string(&config_path).unwrap();
+        assert!(!migrated.contains("rsync_"), "{}", migrated);
+        assert!(!migrated.contains("= false"), "{}", migrated);
+        assert!(migrated.contains("delete = true"));
+        assert!(migrated.contains("timeout = 300"));
+        assert!(migrated.contains("*.tmp"));
+
+        // The original is kept as a backup
+        let backup = std::fs::read_to_string(config_path.with_extension("toml.bak")).unwrap();
+        assert_eq!(backup, LEGACY_CONFIG);
+
+        // Effective flags are unchanged
+        let config: Config = toml::from_str(&migrated).unwrap();
+        let flags = config.sync.custom["structures"].to_rsync_flags(None);
+        assert!(flags.delete);
+        assert!(!flags.compress);
+        assert_eq!(flags.timeout, Some(300));
+        assert_eq!(flags.exclude, vec!["*.tmp".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_validate_strict_fails_on_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            r#"
+[sync.custom.structures]
+url = "rsync.wwpdb.org::ftp_data/structures/"
+dest = "/absolute/structures"
+"#,
+        )
+        .unwrap();
+
+        let lenient = ValidateOptions::default();
+        let strict = ValidateOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(
+            run_validate(Some(config_path.clone()), lenient, &mut Vec::new())
+                .await
+                .is_ok()
+        );
+        assert!(
+            run_validate(Some(config_path.clone()), strict, &mut Vec::new())
+                .await
+                .is_err()
+        );
+
+        // The config option enables strict mode without the flag
+        let mut content = std::fs::read_to_string(&config_path).unwrap();
+        content.push_str("\n[validation]\nwarnings_as_errors = true\n");
+        std::fs::write(&config_path, content).unwrap();
+        assert!(run_validate(Some(config_path), lenient, &mut Vec::new())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validate_failures_exit_with_code_2() {
+        let temp_dir = TempDir::new().unwrap();
+        let warnings = temp_dir.path().join("warnings.toml");
+        std::fs::write(&warnings, "[paths]\npdb_dir = \"/data/pdb\"\n").unwrap();
+        let errors = temp_dir.path().join("errors.toml");
+        std::fs::write(
+            &errors,
+            "[sync.custom.bad]\nurl = \"not-a-url\"\ndest = \"bad\"\n",
+        )
+        .unwrap();
+
+        let strict = ValidateOptions {
+            strict: true,
+            ..Default::default()
+        };
+        for (path, options) in [(&warnings, strict), (&errors, ValidateOptions::default())] {
+            let err = run_validate(Some(path.clone()), options, &mut Vec::new())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PdbSyncError::Validation(_)), "{:?}", err);
+            assert_eq!(err.exit_code(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_json_output_parses() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        for pretty in [false, true] {
+            let options = ValidateOptions {
+                json: true,
+                pretty,
+                ..Default::default()
+            };
+            let mut out = Vec::new();
+            run_validate(Some(config_path.clone()), options, &mut out)
+                .await
+                .unwrap();
+
+            let out = String::from_utf8(out).unwrap();
+            assert_eq!(out.trim_end().contains('\n'), pretty, "{}", out);
+            let result: ValidationResult = serde_json::from_str(&out).unwrap();
+            assert!(result.valid);
+            assert!(result
+                .checks
+                .iter()
+                .any(|c| c.name == "Config name 'structures'"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_json_reports_load_failures() {
+        let temp_dir = TempDir::new().unwrap();
+        let unparsable = temp_dir.path().join("unparsable.toml");
+        std::fs::write(&unparsable, "[sync.custom.bad\n").unwrap();
+        let bad_preset = temp_dir.path().join("bad_preset.toml");
+        std::fs::write(
+            &bad_preset,
+            "[sync.custom.bad]\nurl = \"rsync.example.org::pdb/\"\ndest = \"bad\"\npreset = \"turbo\"\n",
+        )
+        .unwrap();
+        let bad_flags = temp_dir.path().join("bad_flags.toml");
+        std::fs::write(
+            &bad_flags,
+            "[sync.custom.bad]\nurl = \"rsync.example.org::pdb/\"\ndest = \"bad\"\n\n[sync.custom.bad.options]\nchecksum = true\nsize_only = true\n",
+        )
+        .unwrap();
+
+        for path in [unparsable, bad_preset, bad_flags] {
+            let options = ValidateOptions {
+                json: true,
+                ..Default::default()
+            };
+            let mut out = Vec::new();
+            let err = run_validate(Some(path.clone()), options, &mut out)
+                .await
+                .unwrap_err();
+
+            let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+            assert_eq!(report["valid"], false, "{}", path.display());
+            assert_eq!(report["errors"], serde_json::json!([err.to_string()]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_dry_run_writes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("nested/config.toml");
+
+        run_init(Some(config_path.clone()), false, false, None, true)
+            .await
+            .unwrap();
+        assert!(!config_path.exists());
+        assert!(!config_path.parent().unwrap().exists());
+
+        // Existing configs are neither overwritten nor backed up
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "# existing\n").unwrap();
+        run_init(Some(config_path.clone()), true, true, None, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            "# existing\n"
+        );
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_dry_run_writes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        run_migrate(Some(config_path.clone()), true).await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            LEGACY_CONFIG
+        );
+        assert!(!config_path.with_extension("toml.bak").exists());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_is_idempotent() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        run_migrate(Some(config_path.clone()), false).await.unwrap();
+        let first = std::fs::read_to_string(&config_path).unwrap();
+
+        run_migrate(Some(config_path.clone()), false).await.unwrap();
+        let second = std::fs::read_to_string(&config_path).unwrap();
+
+        assert_eq!(first, second);
+        // The second run made no changes, so the backup still holds the original
+        let backup = std::fs::read_to_string(config_path.with_extension("toml.bak")).unwrap();
+        assert_eq!(backup, LEGACY_CONFIG);
+    }
+
     #[tokio::test]
     async fn test_init_creates_config() {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("config.toml");
 
-        let result = run_init(Some(config_path.clone()), false, false, None).await;
+        let result = run_init(Some(config_path.clone()), false, false, None, false).await;
         assert!(result.is_ok());
         assert!(config_path.exists());
 
@@ -658,7 +1056,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("config.toml");
 
-        let result = run_init(Some(config_path.clone()), false, true, None).await;
+        let result = run_init(Some(config_path.clone()), false, true, None, false).await;
         assert!(result.is_ok());
 
         let content = std::fs::read_to_string(&config_path).unwrap();
@@ -673,7 +1071,7 @@ mod tests {
         let config_path = temp_dir.path().join("config.toml");
         let pdb_dir = PathBuf::from("/custom/pdb/path");
 
-        let result = run_init(Some(config_path.clone()), false, true, Some(pdb_dir)).await;
+        let result = run_init(Some(config_path.clone()), false, true, Some(pdb_dir), false).await;
         assert!(result.is_ok());
 
         let content = std::fs::read_to_string(&config_path).unwrap();
@@ -688,7 +1086,7 @@ mod tests {
         // Create existing file
         std::fs::write(&config_path, "existing content").unwrap();
 
-        let result = run_init(Some(config_path.clone()), false, false, None).await;
+        let result = run_init(Some(config_path.clone()), false, false, None, false).await;
         assert!(result.is_err());
 
         // Content should be unchanged
@@ -704,7 +1102,7 @@ mod tests {
         // Create existing file
         std::fs::write(&config_path, "existing content").unwrap();
 
-        let result = run_init(Some(config_path.clone()), true, false, None).await;
+        let result = run_init(Some(config_path.clone()), true, false, None, false).await;
         assert!(result.is_ok());
 
         // Content should be new
@@ -723,7 +1121,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let config_path = temp_dir.path().join("nested/dir/config.toml");
 
-        let result = run_init(Some(config_path.clone()), false, false, None).await;
+        let result = run_init(Some(config_path.clone()), false, false, None, false).await;
         assert!(result.is_ok());
         assert!(config_path.exists());
     }
@@ -760,7 +1158,7 @@ mod tests {
     fn test_print_sync_targets_empty() {
         let config = Config::default();
         // Should not panic with empty config
-        print_sync_targets(&config);
+        print_sync_targets(&config, &[]);
     }
 
     #[test]
@@ -777,7 +1175,7 @@ mod tests {
             },
         );
         // Should not panic
-        print_sync_targets(&config);
+        print_sync_targets(&config, &[]);
     }
 
     #[test]
@@ -801,6 +1199,6 @@ mod tests {
             },
         );
         // Should not panic; output is sorted alphabetically
-        print_sync_targets(&config);
+        print_sync_targets(&config, &[]);
     }
 }
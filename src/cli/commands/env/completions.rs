@@ -0,0 +1,67 @@
+//! Shell completion script generation.
+
+use crate::cli::args::cli_command;
+use crate::error::{PdbSyncError, Result};
+use clap_complete::Shell;
+use std::io::Write;
+
+/// Binary name the completion scripts are registered for.
+const BIN_NAME: &str = "pdb-sync";
+
+/// Generate the completion script for `shell`.
+pub fn completion_script(shell: Shell) -> Vec<u8> {
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut cli_command(), BIN_NAME, &mut script);
+    script
+}
+
+/// Print the completion script for `shell`, or for the shell in `$SHELL`.
+pub fn run_completions(shell: Option<Shell>) -> Result<()> {
+    let shell = shell.or_else(Shell::from_env).ok_or_else(|| {
+        PdbSyncError::InvalidInput(
+            "Could not detect the shell from $SHELL; pass one explicitly (e.g. `pdb-sync env completions zsh`)"
+                .to_string(),
+        )
+    })?;
+
+    std::io::stdout()
+        .lock()
+        .write_all(&completion_script(shell))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completions_cover_subcommands() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = String::from_utf8(completion_script(shell)).unwrap();
+
+            assert!(script.contains(BIN_NAME), "{}", shell);
+            for subcommand in ["sync", "config", "presets", "doctor", "completions"] {
+                assert!(script.contains(subcommand), "{}: {}", shell, subcommand);
+            }
+        }
+    }
+
+    #[test]
+    fn test_completions_cover_value_enums() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = String::from_utf8(completion_script(shell)).unwrap();
+
+            // DataType values (env doctor --data-type) and MirrorId values (formats --mirror)
+            for value in [
+                "chemical-components",
+                "structure-factors",
+                "rcsb",
+                "pdbj",
+                "pdbe",
+                "wwpdb",
+            ] {
+                assert!(script.contains(value), "{}: {}", shell, value);
+            }
+        }
+    }
+}
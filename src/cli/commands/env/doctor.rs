@@ -3,6 +3,8 @@
 use crate::context::AppContext;
 use crate::error::{PdbSyncError, Result};
 use colored::Colorize;
+use pdb_sync::{DataType, Layout};
+use std::path::PathBuf;
 use std::process::Command;
 
 /// Test file name for checking directory writability.
@@ -220,9 +222,52 @@ fn check_pdb_dir(pdb_dir: &std::path::Path) -> Check {
     }
 }
 
+/// Directory a data type syncs into: its `paths.data_type_dirs` entry, or
+/// its wwPDB subpath under `pdb_dir`.
+fn data_type_dir(ctx: &AppContext, data_type: DataType) -> PathBuf {
+    ctx.config
+        .paths
+        .data_type_dirs
+        .get(&data_type.to_string())
+        .cloned()
+        .unwrap_or_else(|| ctx.pdb_dir.join(data_type.rsync_subpath(Layout::default())))
+}
+
+/// Check a data type directory is writable. A missing one is only a warning,
+/// since the first sync creates it.
+fn check_data_type_dir(data_type: DataType, dir: &std::path::Path) -> Check {
+    let mut check = if dir.exists() {
+        check_pdb_dir(dir)
+    } else {
+        Check {
+            name: String::new(),
+            status: CheckStatus::Warn,
+            message: format!("Not created yet: {}", dir.display()),
+        }
+    };
+    check.name = data_type.to_string();
+    check
+}
+
 /// Run the environment diagnostics command.
-pub fn run_doctor(ctx: AppContext) -> Result<()> {
-    let checks = vec![check_rsync(), check_config(), check_pdb_dir(&ctx.pdb_dir)];
+pub fn run_doctor(ctx: AppContext, data_types: &[DataType]) -> Result<()> {
+    let mut checks = vec![check_rsync(), check_config()];
+    if let Some(path) = crate::config::ConfigLoader::project_config_path() {
+        checks.push(Check {
+            name: "project".to_string(),
+            status: CheckStatus::Pass,
+            message: format!("Found: {}", path.display()),
+        });
+    }
+    let mut pdb_dir = check_pdb_dir(&ctx.pdb_dir);
+    pdb_dir.message = format!("{} (from {})", pdb_dir.message, ctx.pdb_dir_source.origin());
+    checks.push(pdb_dir);
+    for &data_type in data_types {
+        checks.push(check_data_type_dir(
+            data_type,
+            &data_type_dir(&ctx, data_type),
+        ));
+    }
 
     let report = DoctorReport::new(checks);
     report.print();
@@ -323,4 +368,16 @@ mod tests {
         assert_eq!(check.status, CheckStatus::Fail);
         assert!(check.message.contains("Does not exist"));
     }
+
+    #[test]
+    fn test_data_type_dir_missing_warns() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let check = check_data_type_dir(DataType::ChemicalComponents, temp.path());
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert_eq!(check.name, "chemical-components");
+
+        let check = check_data_type_dir(DataType::Structures, &temp.path().join("structures"));
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.message.contains("Not created yet"));
+    }
 }
@@ -1,5 +1,7 @@
 //! Environment command handlers.
 
+pub mod completions;
 pub mod doctor;
 
+pub use completions::run_completions;
 pub use doctor::run_doctor;
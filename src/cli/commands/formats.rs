@@ -0,0 +1,133 @@
+//! Supported file formats listing.
+
+use crate::error::Result;
+use crate::files::FileFormat;
+use crate::mirrors::{Mirror, MirrorId};
+use pdb_sync::utils::to_json;
+use serde::Serialize;
+
+/// Metadata describing a single supported file format.
+#[derive(Debug, Clone, Serialize)]
+pub struct FormatInfo {
+    pub name: String,
+    pub description: String,
+    pub extension: String,
+    pub compressed: bool,
+    pub base_format: String,
+    pub mirrors: Vec<String>,
+}
+
+impl FormatInfo {
+    /// Build the metadata for a format.
+    pub fn new(format: FileFormat) -> Self {
+        let mirrors = MirrorId::all()
+            .iter()
+            .filter(|&&id| Mirror::get(id).serves_format(format))
+            .map(|id| id.to_string())
+            .collect();
+
+        Self {
+            name: format.to_string(),
+            description: format.description().to_string(),
+            extension: format.extension().to_string(),
+            compressed: format.is_compressed(),
+            base_format: format.base_format().to_string(),
+            mirrors,
+        }
+    }
+}
+
+/// Collect metadata for every supported format, or only those `mirror` serves.
+pub fn list_formats(mirror: Option<MirrorId>) -> Vec<FormatInfo> {
+    FileFormat::all()
+        .iter()
+        .filter(|&&format| mirror.is_none_or(|id| Mirror::get(id).serves_format(format)))
+        .map(|&format| FormatInfo::new(format))
+        .collect()
+}
+
+/// Print the format table in human-readable form.
+fn print_formats(formats: &[FormatInfo]) {
+    println!("Supported formats ({}):", formats.len());
+    println!();
+    println!(
+        "{:<10} {:<10} {:<11} {:<8} Mirrors",
+        "Name", "Extension", "Compressed", "Base"
+    );
+    println!("{}", "-".repeat(64));
+
+    for info in formats {
+        println!(
+            "{:<10} {:<10} {:<11} {:<8} {}",
+            info.name,
+            format!(".{}", info.extension),
+            if info.compressed { "yes" } else { "no" },
+            info.base_format,
+            info.mirrors.join(", ")
+        );
+    }
+}
+
+/// Run the formats command.
+pub fn run_formats(mirror: Option<MirrorId>, json: bool, pretty: bool) -> Result<()> {
+    let formats = list_formats(mirror);
+
+    if json {
+        println!("{}", to_json(&formats, pretty)?);
+    } else {
+        print_formats(&formats);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_formats_covers_all_variants() {
+        let formats = list_formats(None);
+        assert_eq!(formats.len(), FileFormat::all().len());
+
+        for format in FileFormat::all() {
+            let info = formats
+                .iter()
+                .find(|i| i.name == format.to_string())
+                .expect("format missing from listing");
+            assert_eq!(info.extension, format.extension());
+            assert_eq!(info.compressed, format.is_compressed());
+            assert_eq!(info.base_format, format.base_format().to_string());
+            assert!(!info.mirrors.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_bcif_only_served_by_rcsb() {
+        let info = FormatInfo::new(FileFormat::Bcif);
+        assert_eq!(info.mirrors, vec!["rcsb".to_string()]);
+
+        let info = FormatInfo::new(FileFormat::CifGz);
+        assert_eq!(info.mirrors.len(), MirrorId::all().len());
+    }
+
+    #[test]
+    fn test_list_formats_for_mirror() {
+        let names = |mirror| -> Vec<String> {
+            list_formats(Some(mirror))
+                .into_iter()
+                .map(|info| info.name)
+                .collect()
+        };
+        assert!(names(MirrorId::Rcsb).contains(&"bcif".to_string()));
+        assert!(!names(MirrorId::Pdbe).contains(&"bcif".to_string()));
+        assert!(names(MirrorId::Pdbe).contains(&"cif-gz".to_string()));
+    }
+
+    #[test]
+    fn test_list_formats_json() {
+        let json = serde_json::to_string(&list_formats(None)).unwrap();
+        assert!(json.contains("\"extension\":\"cif.gz\""));
+        assert!(json.contains("\"compressed\":true"));
+    }
+}
@@ -2,4 +2,6 @@
 
 pub mod config;
 pub mod env;
+pub mod formats;
+pub mod presets;
 pub mod sync;
@@ -0,0 +1,221 @@
+//! Preset listing and inspection.
+//!
+//! Covers both built-in profile presets (sync sources added with
+//! `sync --profile-add`) and rsync flag presets (`preset = "..."` in config).
+
+use crate::error::{PdbSyncError, Result};
+use crate::sync::presets::{get_all_presets, get_preset, ProfilePreset};
+use crate::sync::{RsyncFlags, RsyncPreset};
+use pdb_sync::utils::to_json;
+use serde::Serialize;
+
+/// An rsync flag preset with its resolved flags.
+#[derive(Debug, Clone, Serialize)]
+pub struct RsyncPresetInfo {
+    pub name: String,
+    pub description: String,
+    pub flags: RsyncFlags,
+    /// The rsync arguments the flags render to.
+    pub args: Vec<String>,
+}
+
+impl RsyncPresetInfo {
+    /// Build the resolved view of a preset.
+    pub fn new(preset: RsyncPreset) -> Self {
+        let flags = preset.to_flags();
+        Self {
+            name: preset.as_str().to_string(),
+            description: preset.description().to_string(),
+            args: flags.to_args(),
+            flags,
+        }
+    }
+}
+
+/// A profile preset with the rsync command it expands to.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfilePresetInfo {
+    #[serde(flatten)]
+    pub preset: ProfilePreset,
+    /// Flags used when the profile is added (no preset or options are set).
+    pub flags: RsyncFlags,
+    pub args: Vec<String>,
+}
+
+impl ProfilePresetInfo {
+    /// Build the resolved view of a profile preset.
+    pub fn new(preset: ProfilePreset) -> Self {
+        let flags = RsyncFlags::default();
+        Self {
+            args: flags.to_args(),
+            flags,
+            preset,
+        }
+    }
+}
+
+/// All presets, grouped by kind.
+#[derive(Debug, Clone, Serialize)]
+pub struct PresetList {
+    pub profiles: Vec<ProfilePresetInfo>,
+    pub rsync_presets: Vec<RsyncPresetInfo>,
+}
+
+/// A single resolved preset.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PresetDetail {
+    Profile(ProfilePresetInfo),
+    Rsync(RsyncPresetInfo),
+}
+
+/// Collect every preset.
+pub fn list_all() -> PresetList {
+    PresetList {
+        profiles: get_all_presets()
+            .into_iter()
+            .map(ProfilePresetInfo::new)
+            .collect(),
+        rsync_presets: RsyncPreset::all()
+            .iter()
+            .map(|&p| RsyncPresetInfo::new(p))
+            .collect(),
+    }
+}
+
+/// Resolve a preset by name, checking profiles first and then rsync presets.
+pub fn resolve(name: &str) -> Option<PresetDetail> {
+    if let Some(profile) = get_preset(name) {
+        return Some(PresetDetail::Profile(ProfilePresetInfo::new(profile)));
+    }
+    RsyncPreset::from_str(name).map(|p| PresetDetail::Rsync(RsyncPresetInfo::new(p)))
+}
+
+/// Render rsync arguments for display.
+fn render_args(args: &[String]) -> String {
+    if args.is_empty() {
+        "(none)".to_string()
+    } else {
+        args.join(" ")
+    }
+}
+
+/// Run the presets list command.
+pub fn run_list(json: bool, pretty: bool) -> Result<()> {
+    let list = list_all();
+
+    if json {
+        println!("{}", to_json(&list, pretty)?);
+        return Ok(());
+    }
+
+    println!("Profile presets ({}):", list.profiles.len());
+    println!();
+    for info in &list.profiles {
+        println!("Name: {}", info.preset.name);
+        println!("  Description: {}", info.preset.description);
+        println!("  URL: {}", info.preset.url);
+        println!("  Destination: {}", info.preset.dest);
+        println!();
+    }
+
+    println!("Rsync flag presets ({}):", list.rsync_presets.len());
+    println!();
+    for info in &list.rsync_presets {
+        println!("Name: {}", info.name);
+        println!("  Description: {}", info.description);
+        println!("  Flags: {}", render_args(&info.args));
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Run the presets show command.
+pub fn run_show(name: &str, json: bool, pretty: bool) -> Result<()> {
+    let detail = resolve(name).ok_or_else(|| {
+        PdbSyncError::InvalidInput(format!(
+            "Preset '{}' not found. Use 'pdb-sync presets list' to see available presets.",
+            name
+        ))
+    })?;
+
+    if json {
+        println!("{}", to_json(&detail, pretty)?);
+        return Ok(());
+    }
+
+    match detail {
+        PresetDetail::Profile(info) => {
+            println!("Profile preset: {}", info.preset.name);
+            println!("  Description: {}", info.preset.description);
+            println!("  URL: {}", info.preset.url);
+            println!("  Destination: {}", info.preset.dest);
+            let mut command = vec!["rsync".to_string(), "-ah".to_string()];
+            command.extend(info.args.iter().cloned());
+            command.push(info.preset.url.clone());
+            command.push(format!("<pdb_dir>/{}", info.preset.dest));
+            println!("  Command: {}", command.join(" "));
+        }
+        PresetDetail::Rsync(info) => {
+            println!("Rsync flag preset: {}", info.name);
+            println!("  Description: {}", info.description);
+            println!("  Flags: {}", render_args(&info.args));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsync_presets_resolve_and_render() {
+        let expected: &[(&str, &[&str])] = &[
+            ("safe", &["-z", "-c", "--partial", "--verbose"]),
+            ("fast", &["--delete", "-z", "--partial", "--quiet"]),
+            ("minimal", &[]),
+            (
+                "conservative",
+                &["-z", "-c", "--partial", "--backup", "--verbose"],
+            ),
+        ];
+
+        for (name, args) in expected {
+            match resolve(name) {
+                Some(PresetDetail::Rsync(info)) => {
+                    assert_eq!(info.name, *name);
+                    assert_eq!(info.args, *args, "args for preset '{}'", name);
+                }
+                other => panic!("preset '{}' resolved to {:?}", name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_presets_resolve() {
+        for profile in get_all_presets() {
+            match resolve(&profile.name) {
+                Some(PresetDetail::Profile(info)) => assert_eq!(info.preset.url, profile.url),
+                other => panic!("profile '{}' resolved to {:?}", profile.name, other),
+            }
+        }
+        assert!(resolve("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_list_all_json() {
+        let list = list_all();
+        assert_eq!(list.rsync_presets.len(), RsyncPreset::all().len());
+
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!(json["rsync_presets"][1]["name"], "fast");
+        assert_eq!(json["rsync_presets"][1]["flags"]["delete"], true);
+        assert!(json["profiles"][0]["url"].is_string());
+
+        let detail = serde_json::to_value(resolve("safe").unwrap()).unwrap();
+        assert_eq!(detail["kind"], "rsync");
+    }
+}
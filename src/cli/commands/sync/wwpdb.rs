@@ -1,16 +1,27 @@
 //! Custom rsync sync handler.
 
+use std::io::Write;
+use std::path::Path;
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::{DateTime, NaiveDateTime, Utc};
+use tempfile::NamedTempFile;
 use tokio::process::Command;
 use tokio::sync::Semaphore;
 
 use crate::cli::args::SyncArgs;
 use crate::context::AppContext;
 use crate::error::{PdbSyncError, Result};
-use crate::sync::{parse_rsync_stats, SyncPlan};
+use crate::sync::listing::{
+    files_missing_from, files_not_newer_than, parse_list_only, RemoteEntry,
+};
+use crate::sync::mtime::detect_modify_window;
+use crate::sync::plan::{RsyncStats, DIFF_OUT_FORMAT};
+use crate::sync::progress::{relay_prefixed_stderr, relay_progress, tee_stats};
+use crate::sync::split::{aggregate_stats, split_subtrees};
+use crate::sync::{parse_rsync_stats, parse_sync_diff, RsyncFlags, SyncDiff, SyncPlan};
 
 use super::common::validate_subpath;
 
@@ -29,21 +40,21 @@ fn calculate_retry_delay(attempt: u32, fixed_delay: Option<u32>) -> u32 {
 }
 
 /// Execute an async operation with retry logic.
-async fn execute_with_retry<F, Fut>(
+async fn execute_with_retry<F, Fut, T>(
     mut execute_fn: F,
     max_retries: u32,
     retry_delay: Option<u32>,
     name: &str,
-) -> Result<()>
+) -> Result<T>
 where
     F: FnMut() -> Fut,
-    Fut: std::future::Future<Output = Result<()>>,
+    Fut: std::future::Future<Output = Result<T>>,
 {
     let mut last_error = None;
 
     for attempt in 0..=max_retries {
         match execute_fn().await {
-            Ok(()) => return Ok(()),
+            Ok(value) => return Ok(value),
             Err(e) => {
                 // Check if error is retriable and we haven't exhausted retries
                 if e.is_retriable() && attempt < max_retries {
@@ -70,28 +81,481 @@ where
         .unwrap_or_else(|| PdbSyncError::Job("Retry loop exhausted without error".to_string())))
 }
 
+/// Temporary `--files-from` list for point-in-time and `--only-missing`
+/// syncs, removed on drop.
+///
+/// The file is created with a random name and owner-only permissions, and
+/// must outlive every rsync process that reads it.
+struct FileList {
+    file: NamedTempFile,
+}
+
+impl FileList {
+    /// Add the file list to an rsync command.
+    fn apply_to_command(&self, cmd: &mut Command) {
+        // --files-from disables the recursion implied by -a; -r keeps --delete usable
+        cmd.arg("-r").arg("--files-from").arg(self.file.path());
+    }
+}
+
+/// Source URL with exactly one trailing slash.
+///
+/// Listing such a URL yields paths relative to its contents, which is what
+/// `--files-from` and the per-subtree split expect. Without the slash rsync
+/// lists the source directory itself, prefixing every path with its name.
+fn source_root(url: &str) -> String {
+    format!("{}/", url.trim_end_matches('/'))
+}
+
+/// List the remote source and write the selected files to a temporary
+/// `--files-from` list.
+///
+/// `root` must be a [`source_root`] URL, and the transfer must use the same
+/// URL so the listed paths resolve.
+async fn build_file_list(
+    name: &str,
+    root: &str,
+    cutoff: Option<NaiveDateTime>,
+    missing_in: Option<&Path>,
+) -> Result<FileList> {
+    let entries = list_remote(root, true).await?;
+    write_file_list(name, &select_files(name, &entries, cutoff, missing_in))
+}
+
+/// Select the files of a remote listing for a `--files-from` list.
+///
+/// Files are selected if they were modified at or before `cutoff` (when
+/// given) and, when `missing_in` is given, do not exist under that directory.
+fn select_files<'a>(
+    name: &str,
+    entries: &'a [RemoteEntry],
+    cutoff: Option<NaiveDateTime>,
+    missing_in: Option<&Path>,
+) -> Vec<&'a str> {
+    let total = entries.iter().filter(|e| !e.is_dir).count();
+    let mut selected = match cutoff {
+        Some(cutoff) => {
+            let selected = files_not_newer_than(entries, cutoff);
+            println!(
+                "[{}] Snapshot at {}: {} of {} files selected",
+                name,
+                cutoff,
+                selected.len(),
+                total
+            );
+            selected
+        }
+        None => entries
+            .iter()
+            .filter(|e| !e.is_dir)
+            .map(|e| e.name.as_str())
+            .collect(),
+    };
+    if let Some(dest) = missing_in {
+        let candidates = selected.len();
+        selected = files_missing_from(selected, dest);
+        println!(
+            "[{}] Only missing: {} of {} files absent from {}",
+            name,
+            selected.len(),
+            candidates,
+            dest.display()
+        );
+    }
+    selected
+}
+
+/// Write `paths` to a new temporary `--files-from` list.
+fn write_file_list(name: &str, paths: &[&str]) -> Result<FileList> {
+    let mut file = tempfile::Builder::new()
+        .prefix(&format!(
+            "pdb-sync-{}-",
+            name.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "_")
+        ))
+        .suffix(".files")
+        .tempfile()?;
+    for path in paths {
+        writeln!(file, "{}", path)?;
+    }
+    file.flush()?;
+
+    Ok(FileList { file })
+}
+
+/// List a remote rsync source with `--list-only`.
+///
+/// A non-recursive listing returns only the direct children of `url`.
+async fn list_remote(url: &str, recursive: bool) -> Result<Vec<RemoteEntry>> {
+    let mode = if recursive { "-a" } else { "--dirs" };
+    let output = Command::new("rsync")
+        .arg(mode)
+        .arg("--list-only")
+        .arg("--no-human-readable")
+        .arg(url)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        return Err(PdbSyncError::Rsync {
+            command: format!("rsync {} --list-only --no-human-readable {}", mode, url),
+            exit_code: output.status.code(),
+            stderr: None,
+        });
+    }
+
+    Ok(parse_list_only(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Sync each top-level directory of `url` with its own rsync process.
+///
+/// Up to `args.max_concurrent_dirs` subtrees run at once; their output is
+/// prefixed with "name/dir". Returns the statistics summed over all subtrees.
+async fn run_split_dirs(
+    name: &str,
+    url: &str,
+    dest_path: &Path,
+    flags: &RsyncFlags,
+    args: &SyncArgs,
+) -> Result<RsyncStats> {
+    use tokio::task::JoinSet;
+
+    let root_url = source_root(url);
+    let tasks = split_subtrees(&root_url, dest_path, &list_remote(&root_url, false).await?);
+    println!(
+        "[{}] Split into {} subtrees (up to {} at a time)",
+        name,
+        tasks.len(),
+        args.max_concurrent_dirs
+    );
+
+    let semaphore = Arc::new(Semaphore::new(args.max_concurrent_dirs.max(1)));
+    let mut join_set = JoinSet::new();
+
+    for task in tasks {
+        let label = task.label(name);
+        let task_flags = task.flags(flags);
+        let semaphore = semaphore.clone();
+        let (progress_interval, retry, retry_delay) =
+            (args.progress_interval, args.retry, args.retry_delay);
+
+        join_set.spawn(async move {
+            let _permit = match semaphore.acquire().await {
+                Ok(permit) => permit,
+                Err(_) => {
+                    return (
+                        label,
+                        Err(PdbSyncError::Job(
+                            "Semaphore closed unexpectedly".to_string(),
+                        )),
+                    )
+                }
+            };
+
+            let execute = || async {
+                tokio::fs::create_dir_all(&task.dest).await?;
+
+                let mut cmd = Command::new("rsync");
+                cmd.arg("-ah");
+                task_flags.apply_to_command(&mut cmd);
+                cmd.arg("--stats")
+                    .arg("--info=progress2")
+                    .arg(&task.url)
+                    .arg(&task.dest);
+
+                let (status, stats_output) =
+                    spawn_rsync(cmd, &label, progress_interval, true, true).await?;
+                if !status.success() {
+                    return Err(PdbSyncError::Rsync {
+                        command: format!("rsync {} {}", task.url, task.dest.display()),
+                        exit_code: status.code(),
+                        stderr: None,
+                    });
+                }
+                parse_rsync_stats(&stats_output)
+            };
+
+            let result = if retry > 0 {
+                execute_with_retry(execute, retry, retry_delay, &label).await
+            } else {
+                execute().await
+            };
+            (label, result)
+        });
+    }
+
+    let mut stats = Vec::new();
+    let mut failed = 0;
+    while let Some(task_result) = join_set.join_next().await {
+        match task_result {
+            Ok((_, Ok(task_stats))) => stats.push(task_stats),
+            Ok((label, Err(e))) => {
+                eprintln!("Error syncing '{}': {}", label, e);
+                failed += 1;
+            }
+            Err(e) => {
+                eprintln!("Task error: {}", e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(PdbSyncError::Job(format!(
+            "{} of {} subtrees of '{}' failed to sync",
+            failed,
+            failed + stats.len(),
+            name
+        )));
+    }
+
+    let total = aggregate_stats(&stats);
+    println!(
+        "[{}] {} subtrees synced: {} files transferred ({} bytes)",
+        name,
+        stats.len(),
+        total.transferred,
+        total.transferred_size
+    );
+    Ok(total)
+}
+
+/// Describe the generated file list in dry-run output.
+fn file_list_dry_run_args(cutoff: Option<NaiveDateTime>, only_missing: bool) -> Vec<String> {
+    let selection = match (cutoff, only_missing) {
+        (Some(cutoff), false) => format!("files not newer than {}", cutoff),
+        (Some(cutoff), true) => format!("missing files not newer than {}", cutoff),
+        (None, true) => "missing files".to_string(),
+        (None, false) => return Vec::new(),
+    };
+    vec!["-r".to_string(), format!("--files-from=<{}>", selection)]
+}
+
+/// Turn off `delete` for `--only-missing` syncs.
+///
+/// A `--files-from` list of missing files would make rsync treat every
+/// existing local file as extraneous.
+fn disable_delete_for_only_missing(flags: &mut RsyncFlags, name: &str) {
+    if flags.delete {
+        eprintln!(
+            "[{}] Note: --only-missing ignores `delete` from the config",
+            name
+        );
+        flags.delete = false;
+    }
+}
+
+/// Set `modify_window` from the destination filesystem's timestamp granularity.
+///
+/// An explicit modify window (from config or CLI) is left untouched.
+fn apply_auto_modify_window(flags: &mut RsyncFlags, dest_path: &Path, name: &str) {
+    if flags.modify_window.is_some() {
+        return;
+    }
+
+    match detect_modify_window(dest_path) {
+        Ok(Some(window)) => {
+            println!(
+                "[{}] Coarse destination timestamps detected, using --modify-window={}",
+                name, window
+            );
+            flags.modify_window = Some(window);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!(
+                "[{}] Warning: could not probe destination timestamps: {}",
+                name, e
+            );
+        }
+    }
+}
+
+/// Run rsync in dry-run mode and parse the itemized changes into a diff.
+async fn preview_diff(
+    url: &str,
+    dest_path: &Path,
+    flags: &RsyncFlags,
+    file_list: Option<&FileList>,
+) -> Result<SyncDiff> {
+    let mut cmd = Command::new("rsync");
+    cmd.arg("-a").arg("--dry-run");
+    flags.apply_to_command(&mut cmd);
+    if let Some(file_list) = file_list {
+        file_list.apply_to_command(&mut cmd);
+    }
+    // Set after user flags so it replaces any --itemize-changes format
+    cmd.arg(DIFF_OUT_FORMAT).arg(url).arg(dest_path);
+
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::inherit());
+
+    let output = cmd.output().await?;
+
+    if !output.status.success() {
+        let mut cmd_args = vec!["-a".to_string(), "--dry-run".to_string()];
+        cmd_args.extend(flags.to_args());
+        cmd_args.push(format!("'{}'", DIFF_OUT_FORMAT));
+        return Err(PdbSyncError::Rsync {
+            command: format!(
+                "rsync {} {} {}",
+                cmd_args.join(" "),
+                url,
+                dest_path.display()
+            ),
+            exit_code: output.status.code(),
+            stderr: None,
+        });
+    }
+
+    Ok(parse_sync_diff(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Progress reporting interval for parallel syncs when none is given.
+const PARALLEL_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Spawn rsync and wait for it, relaying output.
+///
+/// With a progress interval, stdout is piped and progress is printed as
+/// throttled log lines; otherwise output goes straight to the terminal.
+/// In parallel mode both streams are piped and every line is prefixed with
+/// the target name so interleaved output stays readable.
+///
+/// When `capture_stats` is set, the `--stats` summary is returned alongside
+/// the exit status; otherwise the returned string is empty.
+async fn spawn_rsync(
+    mut cmd: Command,
+    name: &str,
+    progress_interval: Option<Duration>,
+    parallel: bool,
+    capture_stats: bool,
+) -> Result<(std::process::ExitStatus, String)> {
+    let interval = match (progress_interval, parallel) {
+        (Some(interval), _) => Some(interval),
+        (None, true) => Some(PARALLEL_PROGRESS_INTERVAL),
+        (None, false) => None,
+    };
+
+    if interval.is_none() && !capture_stats {
+        cmd.stdout(Stdio::inherit());
+        cmd.stderr(Stdio::inherit());
+        return Ok((cmd.spawn()?.wait().await?, String::new()));
+    }
+
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(if parallel {
+        Stdio::piped()
+    } else {
+        Stdio::inherit()
+    });
+    let mut child = cmd.spawn()?;
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let relay_stdout = async {
+        match (stdout, interval) {
+            (Some(stdout), Some(interval)) => relay_progress(stdout, name, interval).await,
+            (Some(stdout), None) => tee_stats(stdout).await,
+            (None, _) => Ok(String::new()),
+        }
+    };
+    let relay_stderr = async {
+        match stderr {
+            Some(stderr) => relay_prefixed_stderr(stderr, name).await,
+            None => Ok(()),
+        }
+    };
+    let (stdout_result, stderr_result) = tokio::join!(relay_stdout, relay_stderr);
+    let stats = stdout_result?;
+    stderr_result?;
+
+    Ok((child.wait().await?, stats))
+}
+
+/// Parsed rsync statistics for one sync, as written by `--stats-json`.
+#[derive(Debug, serde::Serialize)]
+struct SyncStatsReport<'a> {
+    finished_at: DateTime<Utc>,
+    dry_run: bool,
+    #[serde(flatten)]
+    plan: &'a SyncPlan,
+}
+
+/// Write sync statistics as one JSON line to stdout (`-`) or append it to a file.
+fn emit_stats_json(plan: &SyncPlan, dry_run: bool, target: &Path) -> Result<()> {
+    let report = SyncStatsReport {
+        finished_at: Utc::now(),
+        dry_run,
+        plan,
+    };
+    let line = serde_json::to_string(&report)?;
+
+    if target == Path::new("-") {
+        println!("{}", line);
+    } else {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(target)?;
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Catch a missing or invalid PDB directory before rsync writes into it.
+///
+/// Runs with `--dest`, dry runs, plans and previews don't touch it.
+fn ensure_pdb_dir_for(args: &SyncArgs, ctx: &AppContext) -> Result<()> {
+    let writes_to_pdb_dir = args.dest.is_none() && !(args.dry_run || args.plan || args.preview);
+    if writes_to_pdb_dir {
+        ctx.ensure_pdb_dir()?;
+    }
+    Ok(())
+}
+
 /// Run custom rsync sync by name.
 pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result<()> {
+    ensure_pdb_dir_for(&args, &ctx)?;
+    sync_custom(&name, args, ctx, false).await
+}
+
+/// Sync one custom config.
+///
+/// With `prefixed`, every message is prefixed with `[name]` and rsync runs in
+/// parallel mode, so output from concurrent syncs stays readable.
+async fn sync_custom(name: &str, args: SyncArgs, ctx: AppContext, prefixed: bool) -> Result<()> {
     let dest = args.dest.clone().unwrap_or_else(|| ctx.pdb_dir.clone());
+    let heading = |message: &str| {
+        if prefixed {
+            println!("[{}] {}", name, message);
+        } else {
+            println!("\n{}", message);
+        }
+    };
 
     // Find custom config by name
     let custom_config = ctx
         .config
         .sync
         .custom
-        .get(&name)
+        .get(name)
         .ok_or_else(|| PdbSyncError::Config {
             message: format!("Custom sync config '{}' not found", name),
             key: Some("custom".to_string()),
             source: None,
         })?;
 
-    println!("Syncing custom config: {}", name);
-    if let Some(ref desc) = custom_config.description {
-        println!("Description: {}", desc);
+    if prefixed {
+        println!("[{}]", name);
+    } else {
+        println!("Syncing custom config: {}", name);
+        if let Some(ref desc) = custom_config.description {
+            println!("Description: {}", desc);
+        }
+        println!("URL: {}", custom_config.url);
+        println!("Destination: {}/{}", dest.display(), custom_config.dest);
     }
-    println!("URL: {}", custom_config.url);
-    println!("Destination: {}/{}", dest.display(), custom_config.dest);
 
     // Validate destination path to prevent path traversal
     validate_subpath(&custom_config.dest)
@@ -103,22 +567,74 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
     // Merge config defaults with CLI overrides
     let config_flags = custom_config.to_rsync_flags(ctx.config.sync.defaults.as_ref());
     let cli_overrides = args.to_rsync_overrides();
-    let flags = config_flags.merge_with_overrides(&cli_overrides);
+    let mut flags = config_flags.merge_with_overrides(&cli_overrides);
     flags.validate()?;
 
+    // --plan and --preview conflict with --split-dirs at parse time; dry-run
+    // may also come from the config
+    if args.split_dirs && flags.dry_run {
+        return Err(PdbSyncError::InvalidInput(
+            "--split-dirs cannot be combined with dry-run".to_string(),
+        ));
+    }
+
     // Build destination path
     let dest_path = dest.join(&custom_config.dest);
 
+    // The probe writes a file into the destination, so dry runs skip it
+    if args.auto_modify_window && !flags.dry_run {
+        apply_auto_modify_window(&mut flags, &dest_path, name);
+    }
+    if args.only_missing {
+        disable_delete_for_only_missing(&mut flags, name);
+    }
+
+    // File list paths are relative to the source root, so list and transfer
+    // from the same normalized URL
+    let uses_file_list = args.exclude_newer_than.is_some() || args.only_missing;
+    let source_url = if uses_file_list {
+        source_root(&custom_config.url)
+    } else {
+        custom_config.url.clone()
+    };
+
+    // Build the file list (skipped when only printing the command)
+    let file_list = if uses_file_list && (args.plan || args.preview || !flags.dry_run) {
+        Some(
+            build_file_list(
+                name,
+                &source_url,
+                args.exclude_newer_than,
+                args.only_missing.then_some(dest_path.as_path()),
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    // Handle preview mode - summarize itemized changes without executing
+    if args.preview {
+        heading("Preview mode - analyzing changes...");
+        let diff = preview_diff(&source_url, &dest_path, &flags, file_list.as_ref()).await?;
+        println!();
+        diff.print(name);
+        return Ok(());
+    }
+
     // Handle plan mode - show what would change without executing
     if args.plan {
-        println!("\nPlan mode - analyzing changes...");
+        heading("Plan mode - analyzing changes...");
         let mut cmd = Command::new("rsync");
         cmd.arg("-ah")
             .arg("--dry-run")
             .arg("--stats")
             .arg("--itemize-changes");
         flags.apply_to_command(&mut cmd);
-        cmd.arg(&custom_config.url).arg(&dest_path);
+        if let Some(ref file_list) = file_list {
+            file_list.apply_to_command(&mut cmd);
+        }
+        cmd.arg(&source_url).arg(&dest_path);
 
         // Capture output for parsing
         cmd.stdout(Stdio::piped());
@@ -138,7 +654,7 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
                 command: format!(
                     "rsync {} {} {}",
                     cmd_args.join(" "),
-                    custom_config.url,
+                    source_url,
                     dest_path.display()
                 ),
                 exit_code: output.status.code(),
@@ -150,7 +666,7 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
         let stats = parse_rsync_stats(&stdout)?;
 
         let plan = SyncPlan {
-            name: name.clone(),
+            name: name.to_string(),
             url: custom_config.url.clone(),
             dest: custom_config.dest.clone(),
             has_deletions: flags.delete,
@@ -159,19 +675,26 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
 
         println!();
         plan.print();
+        if let Some(ref target) = args.stats_json {
+            emit_stats_json(&plan, true, target)?;
+        }
 
         return Ok(());
     }
 
     // Handle dry-run mode - show command without executing
     if flags.dry_run {
-        println!("\nDry run - would execute:");
+        heading("Dry run - would execute:");
         let mut cmd_args = vec!["-ah".to_string(), "--info=progress2".to_string()];
         cmd_args.extend(flags.to_args());
+        cmd_args.extend(file_list_dry_run_args(
+            args.exclude_newer_than,
+            args.only_missing,
+        ));
         println!(
             "rsync {} {} {}",
             cmd_args.join(" "),
-            custom_config.url,
+            source_url,
             dest_path.display()
         );
         return Ok(());
@@ -186,15 +709,24 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
         let mut cmd = Command::new("rsync");
         cmd.arg("-ah"); // Base archive options
         flags.apply_to_command(&mut cmd); // Apply merged user flags (includes --delete if set)
+        if let Some(ref file_list) = file_list {
+            file_list.apply_to_command(&mut cmd);
+        }
+        if args.stats_json.is_some() {
+            cmd.arg("--stats");
+        }
         cmd.arg("--info=progress2")
-            .arg(custom_config.url.clone())
+            .arg(source_url.clone())
             .arg(dest_path.clone());
 
-        // Execute rsync with real-time output
-        cmd.stdout(Stdio::inherit());
-        cmd.stderr(Stdio::inherit());
-
-        let status = cmd.spawn()?.wait().await?;
+        let (status, stats_output) = spawn_rsync(
+            cmd,
+            name,
+            args.progress_interval,
+            prefixed,
+            args.stats_json.is_some(),
+        )
+        .await?;
 
         if !status.success() {
             let mut cmd_args = vec!["-ah".to_string(), "--info=progress2".to_string()];
@@ -203,7 +735,7 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
                 command: format!(
                     "rsync {} {} {}",
                     cmd_args.join(" "),
-                    custom_config.url,
+                    source_url,
                     dest_path.display()
                 ),
                 exit_code: status.code(),
@@ -211,18 +743,35 @@ pub async fn run_custom(name: String, args: SyncArgs, ctx: AppContext) -> Result
             });
         }
 
-        Ok(())
+        parse_rsync_stats(&stats_output)
+    };
+
+    // Execute split by subtree, with retry, or directly
+    let stats = if args.split_dirs {
+        run_split_dirs(name, &custom_config.url, &dest_path, &flags, &args).await?
+    } else if args.retry > 0 {
+        execute_with_retry(rsync_execute, args.retry, args.retry_delay, name).await?
+    } else {
+        rsync_execute().await?
     };
 
-    // Execute with retry if requested, otherwise execute directly
-    if args.retry > 0 {
-        execute_with_retry(rsync_execute, args.retry, args.retry_delay, &name).await?;
+    if prefixed {
+        println!("[{}]: completed", name);
     } else {
-        rsync_execute().await?;
+        println!();
+        println!("{}: completed", name);
     }
 
-    println!();
-    println!("{}: completed", name);
+    if let Some(ref target) = args.stats_json {
+        let plan = SyncPlan {
+            name: name.to_string(),
+            url: custom_config.url.clone(),
+            dest: custom_config.dest.clone(),
+            has_deletions: flags.delete,
+            stats,
+        };
+        emit_stats_json(&plan, false, target)?;
+    }
 
     Ok(())
 }
@@ -297,6 +846,9 @@ pub async fn run_custom_all(args: SyncArgs, ctx: AppContext) -> Result<()> {
         return Ok(());
     }
 
+    // Prompt once up front rather than from concurrent syncs
+    ensure_pdb_dir_for(&args, &ctx)?;
+
     println!("Syncing {} custom configs...", custom_configs.len());
     println!();
 
@@ -394,7 +946,7 @@ async fn run_custom_all_parallel(
             }
 
             // Run the sync with output prefixing
-            let result = run_custom_with_prefix(&name, args_clone, ctx_clone).await;
+            let result = sync_custom(&name, args_clone, ctx_clone, true).await;
 
             // Update fail_fast if this failed
             if result.is_err() && fail_fast_flag {
@@ -437,143 +989,143 @@ async fn run_custom_all_parallel(
     }
 }
 
-/// Run a single custom sync with output prefixing for parallel execution.
-async fn run_custom_with_prefix(name: &str, args: SyncArgs, ctx: AppContext) -> Result<()> {
-    let dest = args.dest.clone().unwrap_or_else(|| ctx.pdb_dir.clone());
-
-    // Find custom config by name
-    let custom_config = ctx
-        .config
-        .sync
-        .custom
-        .get(name)
-        .ok_or_else(|| PdbSyncError::Config {
-            message: format!("Custom sync config '{}' not found", name),
-            key: Some("custom".to_string()),
-            source: None,
-        })?;
-
-    println!("[{}]", name);
-
-    // Validate destination path to prevent path traversal
-    super::common::validate_subpath(&custom_config.dest)
-        .map_err(|e| PdbSyncError::InvalidInput(format!("Invalid dest path: {}", e)))?;
-
-    // Validate rsync URL format
-    validate_rsync_url(&custom_config.url)?;
-
-    // Merge config defaults with CLI overrides
-    let config_flags = custom_config.to_rsync_flags(ctx.config.sync.defaults.as_ref());
-    let cli_overrides = args.to_rsync_overrides();
-    let flags = config_flags.merge_with_overrides(&cli_overrides);
-    flags.validate()?;
-
-    // Handle plan mode
-    if args.plan {
-        println!("[{}] Plan mode - analyzing changes...", name);
-        let mut cmd = Command::new("rsync");
-        cmd.arg("-ah")
-            .arg("--dry-run")
-            .arg("--stats")
-            .arg("--itemize-changes");
-        flags.apply_to_command(&mut cmd);
-        cmd.arg(&custom_config.url)
-            .arg(dest.join(&custom_config.dest));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, SourcedValue};
+    use clap::Parser;
 
-        cmd.stdout(Stdio::piped());
-        cmd.stderr(Stdio::inherit());
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        sync: SyncArgs,
+    }
 
-        let output = cmd.output().await?;
+    fn sync_args(argv: &[&str]) -> SyncArgs {
+        TestCli::try_parse_from(std::iter::once("pdb-sync").chain(argv.iter().copied()))
+            .unwrap()
+            .sync
+    }
 
-        if !output.status.success() {
-            return Err(PdbSyncError::Rsync {
-                command: format!(
-                    "rsync --dry-run {} {}",
-                    custom_config.url,
-                    dest.join(&custom_config.dest).display()
-                ),
-                exit_code: output.status.code(),
-                stderr: None,
-            });
+    fn custom_context(pdb_dir: &Path) -> AppContext {
+        let config: Config = toml::from_str(
+            r#"
+[sync.custom.test]
+url = "rsync://example.com/module/"
+dest = "test"
+"#,
+        )
+        .unwrap();
+        AppContext {
+            config,
+            pdb_dir: pdb_dir.to_path_buf(),
+            pdb_dir_source: SourcedValue::with_default(pdb_dir.to_path_buf()),
+            assume_yes: false,
         }
+    }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stats = crate::sync::parse_rsync_stats(&stdout)?;
+    #[tokio::test]
+    async fn test_run_custom_creates_pdb_dir_with_yes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let pdb_dir = temp_dir.path().join("pdb");
+
+        // Dry runs leave a missing PDB directory alone
+        let mut args = sync_args(&[]);
+        args.dry_run = true;
+        let ctx = custom_context(&pdb_dir).with_assume_yes(true);
+        assert!(run_custom("missing".to_string(), args, ctx.clone())
+            .await
+            .is_err());
+        assert!(!pdb_dir.exists());
+
+        // The directory is prepared before the target is looked up
+        assert!(run_custom("missing".to_string(), sync_args(&[]), ctx)
+            .await
+            .is_err());
+        assert!(pdb_dir.is_dir());
+    }
 
-        let plan = crate::sync::SyncPlan {
-            name: name.to_string(),
-            url: custom_config.url.clone(),
-            dest: custom_config.dest.clone(),
-            has_deletions: flags.delete,
-            stats,
-        };
+    /// `rsync -a --list-only` output for a source root ending in '/'.
+    const ROOT_LISTING: &str = "\
+drwxr-xr-x          4,096 2024/01/15 10:30:00 .
+drwxr-xr-x          4,096 2024/01/15 10:30:00 ab
+-rw-r--r--          1,024 2024/01/10 08:00:00 ab/1abc.cif.gz
+drwxr-xr-x          4,096 2024/01/15 10:30:00 cd
+-rw-r--r--          2,048 2024/01/12 08:00:00 cd/2cde.cif.gz
+";
 
-        println!();
-        plan.print();
-        return Ok(());
+    #[test]
+    fn test_source_root_without_trailing_slash() {
+        assert_eq!(
+            source_root("rsync.wwpdb.org::ftp_data/structures/divided/mmCIF"),
+            "rsync.wwpdb.org::ftp_data/structures/divided/mmCIF/"
+        );
+        assert_eq!(source_root("host::module/dir//"), "host::module/dir/");
+
+        // Paths listed under the root are relative to it, not prefixed with "mmCIF/"
+        let entries = parse_list_only(ROOT_LISTING);
+        let list = write_file_list("test", &select_files("test", &entries, None, None)).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(list.file.path()).unwrap(),
+            "ab/1abc.cif.gz\ncd/2cde.cif.gz\n"
+        );
     }
 
-    // Handle dry-run mode
-    if flags.dry_run {
-        println!("[{}] Dry run - would execute:", name);
-        let mut cmd_args = vec!["-ah".to_string(), "--info=progress2".to_string()];
-        cmd_args.extend(flags.to_args());
-        println!(
-            "rsync {} {} {}",
-            cmd_args.join(" "),
-            custom_config.url,
-            dest.join(&custom_config.dest).display()
+    #[test]
+    fn test_select_only_missing_files_relative_to_dest() {
+        let dest = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dest.path().join("ab")).unwrap();
+        std::fs::write(dest.path().join("ab/1abc.cif.gz"), "").unwrap();
+
+        let entries = parse_list_only(ROOT_LISTING);
+        assert_eq!(
+            select_files("test", &entries, None, Some(dest.path())),
+            vec!["cd/2cde.cif.gz"]
         );
-        return Ok(());
     }
 
-    // Build destination path
-    let dest_path = dest.join(&custom_config.dest);
-
-    // Create destination directory
-    tokio::fs::create_dir_all(&dest_path).await?;
-
-    // Prepare rsync command arguments for execution
-    let rsync_execute = || async {
-        // Build rsync command
-        let mut cmd = Command::new("rsync");
-        cmd.arg("-ah");
-        flags.apply_to_command(&mut cmd);
-        cmd.arg("--info=progress2")
-            .arg(custom_config.url.clone())
-            .arg(dest_path.clone());
-
-        // Execute rsync with real-time output
-        cmd.stdout(Stdio::inherit());
-        cmd.stderr(Stdio::inherit());
-
-        let status = cmd.spawn()?.wait().await?;
+    #[test]
+    fn test_file_list_is_unique_and_removed_on_drop() {
+        let a = write_file_list("a/b", &["ab/1abc.cif.gz", "cd/2cde.cif.gz"]).unwrap();
+        let b = write_file_list("a/b", &[]).unwrap();
+        let path = a.file.path().to_path_buf();
+
+        assert_ne!(path, b.file.path());
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("pdb-sync-a_b-"));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "ab/1abc.cif.gz\ncd/2cde.cif.gz\n"
+        );
 
-        if !status.success() {
-            return Err(PdbSyncError::Rsync {
-                command: format!("rsync {} {}", custom_config.url, dest_path.display()),
-                exit_code: status.code(),
-                stderr: None,
-            });
-        }
+        drop(a);
+        assert!(!path.exists());
+    }
 
-        Ok(())
-    };
+    #[tokio::test]
+    async fn test_dry_run_leaves_destination_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut args = sync_args(&["test", "--auto-modify-window", "--delete"]);
+        args.dry_run = true;
 
-    // Execute with retry if requested, otherwise execute directly
-    if args.retry > 0 {
-        execute_with_retry(rsync_execute, args.retry, args.retry_delay, name).await?;
-    } else {
-        rsync_execute().await?;
+        sync_custom("test", args, custom_context(dir.path()), false)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
     }
 
-    println!("[{}]: completed", name);
-    Ok(())
-}
+    #[tokio::test]
+    async fn test_split_dirs_rejects_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut args = sync_args(&["test", "--split-dirs"]);
+        args.dry_run = true;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let result = sync_custom("test", args, custom_context(dir.path()), false).await;
+        assert!(matches!(result, Err(PdbSyncError::InvalidInput(_))));
+    }
 
     #[test]
     fn test_validate_standard_rsync() {
@@ -618,6 +1170,31 @@ mod tests {
         assert!(validate_rsync_url("ftp://example.com").is_err());
     }
 
+    #[test]
+    fn test_file_list_dry_run_args() {
+        assert!(file_list_dry_run_args(None, false).is_empty());
+        assert_eq!(
+            file_list_dry_run_args(None, true),
+            vec!["-r", "--files-from=<missing files>"]
+        );
+
+        let cutoff = crate::sync::listing::parse_cutoff("2024-01-15").unwrap();
+        assert_eq!(
+            file_list_dry_run_args(Some(cutoff), true)[1],
+            "--files-from=<missing files not newer than 2024-01-15 23:59:59>"
+        );
+    }
+
+    #[test]
+    fn test_only_missing_disables_delete() {
+        let mut flags = RsyncFlags {
+            delete: true,
+            ..Default::default()
+        };
+        disable_delete_for_only_missing(&mut flags, "test");
+        assert!(!flags.delete);
+    }
+
     #[test]
     fn test_calculate_retry_delay_exponential() {
         // Exponential backoff: 1, 2, 4, 8, 16, 30, 30...
@@ -1,7 +1,28 @@
 use crate::config::Config;
-use crate::error::Result;
+use crate::error::{PdbSyncError, Result};
 use directories::{ProjectDirs, UserDirs};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Config path given explicitly on the command line (`--config`).
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// File name of project-local config files.
+pub const PROJECT_CONFIG_FILE: &str = ".pdb-sync.toml";
+
+/// A config file that was loaded, with its parsed contents.
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    pub path: PathBuf,
+    pub config: Config,
+}
+
+impl ConfigLayer {
+    /// Whether this layer is a project-local `.pdb-sync.toml`.
+    pub fn is_project(&self) -> bool {
+        self.path.file_name() == Some(std::ffi::OsStr::new(PROJECT_CONFIG_FILE))
+    }
+}
 
 pub struct ConfigLoader;
 
@@ -9,17 +30,23 @@ impl ConfigLoader {
     /// Get the config file path
     ///
     /// Priority:
-    /// 1. PDB_SYNC_CONFIG environment variable
-    /// 2. XDG_CONFIG_HOME/pdb-sync/config.toml (if exists)
-    /// 3. ~/.config/pdb-sync/config.toml (XDG default, if exists)
-    /// 4. Platform-specific directory (directories crate)
+    /// 1. `--config` CLI flag
+    /// 2. PDB_SYNC_CONFIG environment variable
+    /// 3. XDG_CONFIG_HOME/pdb-sync/config.toml (if exists)
+    /// 4. ~/.config/pdb-sync/config.toml (XDG default, if exists)
+    /// 5. Platform-specific directory (directories crate)
     pub fn config_path() -> Option<PathBuf> {
-        // 1. Explicit environment variable (highest priority)
+        // 1. Explicit CLI flag (highest priority)
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            return Some(path.clone());
+        }
+
+        // 2. Explicit environment variable
         if let Ok(path) = std::env::var("PDB_SYNC_CONFIG") {
             return Some(PathBuf::from(path));
         }
 
-        // 2. XDG_CONFIG_HOME (if set and config exists)
+        // 3. XDG_CONFIG_HOME (if set and config exists)
         if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
             let path = PathBuf::from(xdg_config).join("pdb-sync/config.toml");
             if path.exists() {
@@ -27,7 +54,7 @@ impl ConfigLoader {
             }
         }
 
-        // 3. XDG default (~/.config/pdb-sync/config.toml, if exists)
+        // 4. XDG default (~/.config/pdb-sync/config.toml, if exists)
         if let Some(user_dirs) = UserDirs::new() {
             let xdg_default = user_dirs.home_dir().join(".config/pdb-sync/config.toml");
             if xdg_default.exists() {
@@ -35,7 +62,7 @@ impl ConfigLoader {
             }
         }
 
-        // 4. Platform-specific directory (directories crate)
+        // 5. Platform-specific directory (directories crate)
         Self::platform_config_dir().map(|dir| dir.join("config.toml"))
     }
 
@@ -44,19 +71,294 @@ impl ConfigLoader {
         ProjectDirs::from("", "", "pdb-sync").map(|dirs| dirs.config_dir().to_path_buf())
     }
 
-    /// Load config from file, or return default if not found
-    pub fn load() -> Result<Config> {
-        let path = match Self::config_path() {
-            Some(p) => p,
-            None => return Ok(Config::default()),
-        };
+    /// The explicitly requested config file, if any.
+    ///
+    /// `--config` takes precedence over the `PDB_SYNC_CONFIG` environment variable.
+    pub fn explicit_path(cli: Option<PathBuf>, env: Option<std::ffi::OsString>) -> Option<PathBuf> {
+        cli.or_else(|| env.filter(|v| !v.is_empty()).map(PathBuf::from))
+    }
+
+    /// Use `path` as the config file for the rest of the process.
+    ///
+    /// The file must exist and parse; it is validated before the override
+    /// is recorded. Only the first call takes effect.
+    pub fn set_path_override(path: PathBuf) -> Result<()> {
+        Self::load_from(&path)?;
+        let _ = CONFIG_PATH_OVERRIDE.set(path);
+        Ok(())
+    }
+
+    /// Load config from an explicit path, failing if it is missing or invalid
+    pub fn load_from(path: &Path) -> Result<Config> {
+        if !path.is_file() {
+            return Err(PdbSyncError::Config {
+                message: format!("Config file not found: {}", path.display()),
+                key: None,
+                source: None,
+            });
+        }
+
+        let content = std::fs::read_to_string(path).map_err(|e| PdbSyncError::Config {
+            message: format!("Failed to read config file {}: {}", path.display(), e),
+            key: None,
+            source: Some(Box::new(e)),
+        })?;
+        toml::from_str(&content).map_err(|e| PdbSyncError::Config {
+            message: format!("Failed to parse config {}: {}", path.display(), e),
+            key: None,
+            source: Some(Box::new(e)),
+        })
+    }
+
+    /// Find the nearest project-local config, starting at `start`.
+    ///
+    /// Parent directories are searched up to and including the repository
+    /// root (the first directory containing `.git`).
+    pub fn find_project_config(start: &Path) -> Option<PathBuf> {
+        for dir in start.ancestors() {
+            let candidate = dir.join(PROJECT_CONFIG_FILE);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Get the project-local config path for the current directory.
+    ///
+    /// Returns `None` when `--config` was given, since an explicit config
+    /// file replaces the whole lookup.
+    pub fn project_config_path() -> Option<PathBuf> {
+        if CONFIG_PATH_OVERRIDE.get().is_some() {
+            return None;
+        }
+        let cwd = std::env::current_dir().ok()?;
+        Self::find_project_config(&cwd)
+    }
+
+    /// Load every config file that applies, lowest precedence first.
+    ///
+    /// This is the global config (if it exists) followed by the project-local
+    /// `.pdb-sync.toml` (if one is found).
+    pub fn load_layers() -> Result<Vec<ConfigLayer>> {
+        let mut layers = Vec::new();
+
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            let config = Self::load_from(path)?;
+            layers.push(ConfigLayer {
+                path: path.clone(),
+                config,
+            });
+            return Ok(layers);
+        }
+
+        if let Some(path) = Self::config_path().filter(|p| p.exists()) {
+            let content = std::fs::read_to_string(&path)?;
+            let config: Config = toml::from_str(&content)?;
+            layers.push(ConfigLayer { path, config });
+        }
 
-        if !path.exists() {
-            return Ok(Config::default());
+        if let Some(path) = Self::project_config_path() {
+            let config = Self::load_from(&path)?;
+            layers.push(ConfigLayer { path, config });
         }
 
-        let content = std::fs::read_to_string(&path)?;
-        let config: Config = toml::from_str(&content)?;
-        Ok(config)
+        Ok(layers)
+    }
+
+    /// Merge config layers, later layers taking precedence.
+    pub fn merge_layers(layers: &[ConfigLayer]) -> Config {
+        layers.iter().fold(Config::default(), |mut merged, layer| {
+            merged.merge(layer.config.clone());
+            merged
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_from_explicit_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("project.toml");
+        std::fs::write(
+            &path,
+            r#"
+[paths]
+pdb_dir = "/data/project-pdb"
+
+[sync.custom.project]
+url = "rsync.example.org::pdb/"
+dest = "project"
+"#,
+        )
+        .unwrap();
+
+        let config = ConfigLoader::load_from(&path).unwrap();
+        assert_eq!(
+            config.paths.pdb_dir,
+            Some(PathBuf::from("/data/project-pdb"))
+        );
+        assert!(config.sync.custom.contains_key("project"));
+        assert_ne!(config.paths.pdb_dir, Config::default().paths.pdb_dir);
+    }
+
+    #[test]
+    fn test_find_project_config_walks_up_to_repo_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let nested = repo.join("analysis/run1");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir(repo.join(".git")).unwrap();
+
+        // A config above the repository root is not picked up
+        std::fs::write(temp_dir.path().join(PROJECT_CONFIG_FILE), "").unwrap();
+        assert_eq!(ConfigLoader::find_project_config(&nested), None);
+
+        std::fs::write(repo.join(PROJECT_CONFIG_FILE), "").unwrap();
+        assert_eq!(
+            ConfigLoader::find_project_config(&nested),
+            Some(repo.join(PROJECT_CONFIG_FILE))
+        );
+
+        // The nearest config wins
+        std::fs::write(nested.join(PROJECT_CONFIG_FILE), "").unwrap();
+        assert_eq!(
+            ConfigLoader::find_project_config(&nested),
+            Some(nested.join(PROJECT_CONFIG_FILE))
+        );
+    }
+
+    #[test]
+    fn test_merge_layers_project_wins() {
+        let global: Config = toml::from_str(
+            r#"
+[paths]
+pdb_dir = "/data/pdb"
+
+[sync.custom.structures]
+url = "rsync.wwpdb.org::ftp_data/structures/"
+dest = "structures"
+
+[sync.custom.emdb]
+url = "data.pdbj.org::rsync/pub/emdb/"
+dest = "emdb"
+"#,
+        )
+        .unwrap();
+        let project: Config = toml::from_str(
+            r#"
+[paths]
+pdb_dir = "/work/project/pdb"
+
+[sync.custom.structures]
+url = "rsync.example.org::mirror/structures/"
+dest = "structures"
+"#,
+        )
+        .unwrap();
+
+        let merged = ConfigLoader::merge_layers(&[
+            ConfigLayer {
+                path: PathBuf::from("/home/user/.config/pdb-sync/config.toml"),
+                config: global,
+            },
+            ConfigLayer {
+                path: PathBuf::from("/work/project/.pdb-sync.toml"),
+                config: project,
+            },
+        ]);
+
+        assert_eq!(
+            merged.paths.pdb_dir,
+            Some(PathBuf::from("/work/project/pdb"))
+        );
+        assert_eq!(
+            merged.sync.custom["structures"].url,
+            "rsync.example.org::mirror/structures/"
+        );
+        assert!(merged.sync.custom.contains_key("emdb"));
+    }
+
+    #[test]
+    fn test_merge_layers_defaults_and_strictness() {
+        let layer = |path: &str, toml_str: &str| ConfigLayer {
+            path: PathBuf::from(path),
+            config: toml::from_str(toml_str).unwrap(),
+        };
+        let global = layer(
+            "/home/user/.config/pdb-sync/config.toml",
+            r#"
+[sync.defaults]
+compress = true
+timeout = 300
+exclude = ["*.tmp"]
+
+[validation]
+warnings_as_errors = true
+"#,
+        );
+
+        // Project options are merged into the global defaults field by field
+        let project = layer(
+            "/work/project/.pdb-sync.toml",
+            r#"
+[sync.defaults]
+timeout = 60
+delete = true
+
+[validation]
+warnings_as_errors = false
+"#,
+        );
+        let merged = ConfigLoader::merge_layers(&[global.clone(), project]);
+        let defaults = merged.sync.defaults.unwrap();
+        assert_eq!(defaults.compress, Some(true));
+        assert_eq!(defaults.timeout, Some(60));
+        assert_eq!(defaults.delete, Some(true));
+        assert_eq!(defaults.exclude, vec!["*.tmp".to_string()]);
+        assert!(!merged.validation.strict());
+
+        // A project file that doesn't mention strictness keeps the global value
+        let project = layer("/work/project/.pdb-sync.toml", "[sync.defaults]\n");
+        let merged = ConfigLoader::merge_layers(&[global, project]);
+        assert!(merged.validation.strict());
+        assert_eq!(merged.sync.defaults.unwrap().timeout, Some(300));
+    }
+
+    #[test]
+    fn test_explicit_path_cli_overrides_env() {
+        assert_eq!(
+            ConfigLoader::explicit_path(Some(PathBuf::from("cli.toml")), Some("env.toml".into())),
+            Some(PathBuf::from("cli.toml"))
+        );
+        assert_eq!(
+            ConfigLoader::explicit_path(None, Some("env.toml".into())),
+            Some(PathBuf::from("env.toml"))
+        );
+        assert_eq!(ConfigLoader::explicit_path(None, Some("".into())), None);
+        assert_eq!(ConfigLoader::explicit_path(None, None), None);
+    }
+
+    #[test]
+    fn test_load_from_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = ConfigLoader::load_from(&temp_dir.path().join("missing.toml"));
+        assert!(matches!(result, Err(PdbSyncError::Config { .. })));
+    }
+
+    #[test]
+    fn test_load_from_invalid_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("bad.toml");
+        std::fs::write(&path, "[paths\npdb_dir = ").unwrap();
+        let result = ConfigLoader::load_from(&path);
+        assert!(matches!(result, Err(PdbSyncError::Config { .. })));
     }
 }
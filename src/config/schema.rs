@@ -57,6 +57,53 @@ use std::path::PathBuf;
 pub struct Config {
     pub paths: PathsConfig,
     pub sync: SyncConfig,
+    #[serde(skip_serializing_if = "ValidationConfig::is_default")]
+    pub validation: ValidationConfig,
+}
+
+/// Settings for `config validate`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationConfig {
+    /// Fail validation when there are warnings, as with `--strict`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warnings_as_errors: Option<bool>,
+}
+
+impl ValidationConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether warnings fail validation (unset means no).
+    pub fn strict(&self) -> bool {
+        self.warnings_as_errors.unwrap_or(false)
+    }
+}
+
+impl Config {
+    /// Merge `other` over this config, with values from `other` winning.
+    ///
+    /// Custom sync configs and data type directories are merged by name,
+    /// and `sync.defaults` field by field.
+    pub fn merge(&mut self, other: Config) {
+        if other.paths.pdb_dir.is_some() {
+            self.paths.pdb_dir = other.paths.pdb_dir;
+        }
+        self.paths.data_type_dirs.extend(other.paths.data_type_dirs);
+        match (&mut self.sync.defaults, other.sync.defaults) {
+            (Some(defaults), Some(other)) => defaults.merge(other),
+            (defaults, other) => {
+                if other.is_some() {
+                    *defaults = other;
+                }
+            }
+        }
+        self.sync.custom.extend(other.sync.custom);
+        if other.validation.warnings_as_errors.is_some() {
+            self.validation.warnings_as_errors = other.validation.warnings_as_errors;
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -113,10 +160,10 @@ pub struct RsyncOptionsConfig {
     /// Change permission flags
     pub chmod: Option<String>,
     /// Exclude patterns
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub exclude: Vec<String>,
     /// Include patterns
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub include: Vec<String>,
     /// File with exclude patterns
     pub exclude_from: Option<String>,
@@ -134,6 +181,38 @@ pub struct RsyncOptionsConfig {
 }
 
 impl RsyncOptionsConfig {
+    /// Merge `other` over these options.
+    ///
+    /// Fields set in `other` win; non-empty pattern lists replace ours.
+    pub fn merge(&mut self, other: RsyncOptionsConfig) {
+        self.delete = other.delete.or(self.delete);
+        self.compress = other.compress.or(self.compress);
+        self.checksum = other.checksum.or(self.checksum);
+        self.size_only = other.size_only.or(self.size_only);
+        self.ignore_times = other.ignore_times.or(self.ignore_times);
+        self.modify_window = other.modify_window.or(self.modify_window);
+        self.partial = other.partial.or(self.partial);
+        self.partial_dir = other.partial_dir.or(self.partial_dir.take());
+        self.max_size = other.max_size.or(self.max_size.take());
+        self.min_size = other.min_size.or(self.min_size.take());
+        self.timeout = other.timeout.or(self.timeout);
+        self.contimeout = other.contimeout.or(self.contimeout);
+        self.backup = other.backup.or(self.backup);
+        self.backup_dir = other.backup_dir.or(self.backup_dir.take());
+        self.chmod = other.chmod.or(self.chmod.take());
+        if !other.exclude.is_empty() {
+            self.exclude = other.exclude;
+        }
+        if !other.include.is_empty() {
+            self.include = other.include;
+        }
+        self.exclude_from = other.exclude_from.or(self.exclude_from.take());
+        self.include_from = other.include_from.or(self.include_from.take());
+        self.verbose = other.verbose.or(self.verbose);
+        self.quiet = other.quiet.or(self.quiet);
+        self.itemize_changes = other.itemize_changes.or(self.itemize_changes);
+    }
+
     /// Convert to RsyncFlags for use in rsync operations.
     ///
     /// Only sets fields that are explicitly provided (Some).
@@ -193,70 +272,160 @@ pub struct CustomRsyncConfig {
 
     // === Legacy format: flat rsync_* fields (for backward compatibility) ===
     /// Delete files that don't exist on the remote
-    #[serde(rename = "rsync_delete", alias = "delete")]
+    #[serde(
+        rename = "rsync_delete",
+        alias = "delete",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_delete: bool,
     /// Compress data during transfer
-    #[serde(rename = "rsync_compress", alias = "compress")]
+    #[serde(
+        rename = "rsync_compress",
+        alias = "compress",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_compress: bool,
     /// Use checksum for file comparison
-    #[serde(rename = "rsync_checksum", alias = "checksum")]
+    #[serde(
+        rename = "rsync_checksum",
+        alias = "checksum",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_checksum: bool,
     /// Compare by size only, ignore timestamps
-    #[serde(rename = "rsync_size_only", alias = "size_only")]
+    #[serde(
+        rename = "rsync_size_only",
+        alias = "size_only",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_size_only: bool,
     /// Always transfer files, ignoring timestamps
-    #[serde(rename = "rsync_ignore_times", alias = "ignore_times")]
+    #[serde(
+        rename = "rsync_ignore_times",
+        alias = "ignore_times",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_ignore_times: bool,
     /// Timestamp tolerance in seconds
-    #[serde(rename = "rsync_modify_window", alias = "modify_window")]
+    #[serde(
+        rename = "rsync_modify_window",
+        alias = "modify_window",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_modify_window: Option<u32>,
     /// Keep partially transferred files
-    #[serde(rename = "rsync_partial", alias = "partial")]
+    #[serde(
+        rename = "rsync_partial",
+        alias = "partial",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_partial: bool,
     /// Directory for partial files
-    #[serde(rename = "rsync_partial_dir", alias = "partial_dir")]
+    #[serde(
+        rename = "rsync_partial_dir",
+        alias = "partial_dir",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_partial_dir: Option<String>,
     /// Maximum file size to transfer
-    #[serde(rename = "rsync_max_size", alias = "max_size")]
+    #[serde(
+        rename = "rsync_max_size",
+        alias = "max_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_max_size: Option<String>,
     /// Minimum file size to transfer
-    #[serde(rename = "rsync_min_size", alias = "min_size")]
+    #[serde(
+        rename = "rsync_min_size",
+        alias = "min_size",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_min_size: Option<String>,
     /// I/O timeout in seconds
-    #[serde(rename = "rsync_timeout", alias = "timeout")]
+    #[serde(
+        rename = "rsync_timeout",
+        alias = "timeout",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_timeout: Option<u32>,
     /// Connection timeout in seconds
-    #[serde(rename = "rsync_contimeout", alias = "contimeout")]
+    #[serde(
+        rename = "rsync_contimeout",
+        alias = "contimeout",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_contimeout: Option<u32>,
     /// Create backups
-    #[serde(rename = "rsync_backup", alias = "backup")]
+    #[serde(
+        rename = "rsync_backup",
+        alias = "backup",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_backup: bool,
     /// Backup directory
-    #[serde(rename = "rsync_backup_dir", alias = "backup_dir")]
+    #[serde(
+        rename = "rsync_backup_dir",
+        alias = "backup_dir",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_backup_dir: Option<String>,
     /// Change permission flags
-    #[serde(rename = "rsync_chmod", alias = "chmod")]
+    #[serde(
+        rename = "rsync_chmod",
+        alias = "chmod",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_chmod: Option<String>,
     /// Exclude patterns
-    #[serde(rename = "rsync_exclude", alias = "exclude", default)]
+    #[serde(
+        rename = "rsync_exclude",
+        alias = "exclude",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub rsync_exclude: Vec<String>,
     /// Include patterns
-    #[serde(rename = "rsync_include", alias = "include", default)]
+    #[serde(
+        rename = "rsync_include",
+        alias = "include",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub rsync_include: Vec<String>,
     /// File with exclude patterns
-    #[serde(rename = "rsync_exclude_from", alias = "exclude_from")]
+    #[serde(
+        rename = "rsync_exclude_from",
+        alias = "exclude_from",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_exclude_from: Option<String>,
     /// File with include patterns
-    #[serde(rename = "rsync_include_from", alias = "include_from")]
+    #[serde(
+        rename = "rsync_include_from",
+        alias = "include_from",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub rsync_include_from: Option<String>,
     /// Verbose output
-    #[serde(rename = "rsync_verbose", alias = "verbose")]
+    #[serde(
+        rename = "rsync_verbose",
+        alias = "verbose",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_verbose: bool,
     /// Quiet mode
-    #[serde(rename = "rsync_quiet", alias = "quiet")]
+    #[serde(
+        rename = "rsync_quiet",
+        alias = "quiet",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_quiet: bool,
     /// Itemize changes
-    #[serde(rename = "rsync_itemize_changes", alias = "itemize_changes")]
+    #[serde(
+        rename = "rsync_itemize_changes",
+        alias = "itemize_changes",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
     pub rsync_itemize_changes: bool,
 }
 
@@ -3,6 +3,8 @@
 //! This module provides types for tracking where configuration values originate from,
 //! which is useful for debugging and improving error messages.
 
+use std::path::PathBuf;
+
 /// Where a configuration value came from.
 ///
 /// The priority order (highest to lowest) is:
@@ -56,13 +58,19 @@ pub struct SourcedValue<T> {
     pub value: T,
     /// Where this value came from
     pub source: FlagSource,
+    /// The config file this value was read from, if any
+    pub file: Option<PathBuf>,
 }
 
 impl<T> SourcedValue<T> {
     /// Create a new sourced value.
     #[allow(dead_code)]
     pub const fn new(value: T, source: FlagSource) -> Self {
-        Self { value, source }
+        Self {
+            value,
+            source,
+            file: None,
+        }
     }
 
     /// Create a sourced value with the default source.
@@ -71,6 +79,7 @@ impl<T> SourcedValue<T> {
         Self {
             value,
             source: FlagSource::Default,
+            file: None,
         }
     }
 
@@ -80,6 +89,7 @@ impl<T> SourcedValue<T> {
         Self {
             value,
             source: FlagSource::Config,
+            file: None,
         }
     }
 
@@ -89,6 +99,7 @@ impl<T> SourcedValue<T> {
         Self {
             value,
             source: FlagSource::Env,
+            file: None,
         }
     }
 
@@ -98,6 +109,7 @@ impl<T> SourcedValue<T> {
         Self {
             value,
             source: FlagSource::CliArg,
+            file: None,
         }
     }
 
@@ -110,6 +122,21 @@ impl<T> SourcedValue<T> {
         SourcedValue {
             value: f(self.value),
             source: self.source,
+            file: self.file,
+        }
+    }
+
+    /// Record the config file this value was read from.
+    pub fn in_file(mut self, file: PathBuf) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Describe where this value came from, including the file if known.
+    pub fn origin(&self) -> String {
+        match self.file {
+            Some(ref file) => format!("{} {}", self.source, file.display()),
+            None => self.source.to_string(),
         }
     }
 
@@ -174,4 +201,18 @@ mod tests {
         assert_eq!(mapped.value, 20);
         assert_eq!(mapped.source, FlagSource::Env);
     }
+
+    #[test]
+    fn test_sourced_value_origin() {
+        let val = SourcedValue::from_env("/data/pdb");
+        assert_eq!(val.origin(), "environment variable");
+
+        let val =
+            SourcedValue::from_config("/data/pdb").in_file(PathBuf::from("/work/.pdb-sync.toml"));
+        assert_eq!(val.origin(), "config file /work/.pdb-sync.toml");
+        assert_eq!(
+            val.map(|v| v.len()).file,
+            Some(PathBuf::from("/work/.pdb-sync.toml"))
+        );
+    }
 }
@@ -1,46 +1,260 @@
-use crate::config::{Config, ConfigLoader};
-use crate::error::Result;
-use std::path::PathBuf;
+use crate::config::loader::ConfigLayer;
+use crate::config::{Config, ConfigLoader, SourcedValue};
+use crate::error::{PdbSyncError, Result};
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 /// Application context that combines configuration, environment variables, and CLI arguments
 #[derive(Clone)]
 pub struct AppContext {
     pub config: Config,
     pub pdb_dir: PathBuf,
+    /// Where `pdb_dir` came from, for diagnostics
+    pub pdb_dir_source: SourcedValue<PathBuf>,
+    /// Answer yes to confirmation prompts (`--yes`)
+    pub assume_yes: bool,
 }
 
 impl AppContext {
     pub async fn new() -> Result<Self> {
-        let config = ConfigLoader::load()?;
-
-        // Priority: config > ENV > default
-        let pdb_dir = config
-            .paths
-            .pdb_dir
-            .clone()
-            .or_else(|| {
-                std::env::var("PDB_SYNC_DIR")
-                    .or_else(|_| {
-                        std::env::var("PDB_DIR").inspect(|_| {
-                            eprintln!("Warning: PDB_DIR is deprecated, use PDB_SYNC_DIR instead");
-                        })
-                    })
-                    .map(PathBuf::from)
-                    .ok()
-            })
-            .unwrap_or_else(|| {
-                directories::UserDirs::new()
-                    .map(|d| d.home_dir().join("pdb"))
-                    .unwrap_or_else(|| PathBuf::from("./pdb"))
-            });
+        let layers = ConfigLoader::load_layers()?;
+        let config = ConfigLoader::merge_layers(&layers);
+        let pdb_dir_source = resolve_pdb_dir(&layers);
 
-        Ok(Self { config, pdb_dir })
+        Ok(Self {
+            config,
+            pdb_dir: pdb_dir_source.value.clone(),
+            pdb_dir_source,
+            assume_yes: false,
+        })
     }
 
     pub fn with_overrides(mut self, pdb_dir: Option<PathBuf>) -> Self {
         if let Some(dir) = pdb_dir {
+            self.pdb_dir_source = SourcedValue::from_cli(dir.clone());
             self.pdb_dir = dir;
         }
         self
     }
+
+    pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    /// Make sure `pdb_dir` is usable before a command writes into it.
+    ///
+    /// Fails if the path exists but is not a directory. If it does not exist,
+    /// it is created when `--yes` was given or the user confirms the prompt.
+    pub fn ensure_pdb_dir(&self) -> Result<()> {
+        let assume_yes = self.assume_yes;
+        self.ensure_pdb_dir_with(|path| assume_yes || prompt_create_dir(path))
+    }
+
+    /// Like [`ensure_pdb_dir`](Self::ensure_pdb_dir), with a custom confirmation.
+    pub fn ensure_pdb_dir_with(&self, confirm: impl FnOnce(&Path) -> bool) -> Result<()> {
+        let dir = &self.pdb_dir;
+
+        if dir.exists() {
+            if !dir.is_dir() {
+                return Err(PdbSyncError::Path(format!(
+                    "PDB directory is not a directory: {}",
+                    dir.display()
+                )));
+            }
+            return Ok(());
+        }
+
+        if !confirm(dir) {
+            return Err(PdbSyncError::Path(format!(
+                "PDB directory does not exist: {} (create it, pass --yes, or use --pdb-dir)",
+                dir.display()
+            )));
+        }
+
+        std::fs::create_dir_all(dir).map_err(|e| {
+            PdbSyncError::Path(format!(
+                "Failed to create PDB directory {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+        eprintln!("Created PDB directory: {}", dir.display());
+        Ok(())
+    }
+}
+
+/// Resolve the PDB directory from config layers and the environment.
+///
+/// Priority: project config > global config > ENV > default
+///
+/// A relative `pdb_dir` in a project config is relative to the directory
+/// containing that file, not to the current directory.
+pub fn resolve_pdb_dir(layers: &[ConfigLayer]) -> SourcedValue<PathBuf> {
+    if let Some(layer) = layers
+        .iter()
+        .rev()
+        .find(|l| l.config.paths.pdb_dir.is_some())
+    {
+        let mut dir = layer.config.paths.pdb_dir.clone().expect("checked above");
+        if let Some(base) = layer.path.parent().filter(|_| layer.is_project()) {
+            if dir.is_relative() {
+                dir = base.join(dir);
+            }
+        }
+        return SourcedValue::from_config(dir).in_file(layer.path.clone());
+    }
+
+    std::env::var("PDB_SYNC_DIR")
+        .or_else(|_| {
+            std::env::var("PDB_DIR").inspect(|_| {
+                eprintln!("Warning: PDB_DIR is deprecated, use PDB_SYNC_DIR instead");
+            })
+        })
+        .map(|dir| SourcedValue::from_env(PathBuf::from(dir)))
+        .unwrap_or_else(|_| {
+            SourcedValue::with_default(
+                directories::UserDirs::new()
+                    .map(|d| d.home_dir().join("pdb"))
+                    .unwrap_or_else(|| PathBuf::from("./pdb")),
+            )
+        })
+}
+
+/// Ask on the terminal whether to create `path`. Returns false when not interactive.
+fn prompt_create_dir(path: &Path) -> bool {
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+
+    eprint!(
+        "PDB directory {} does not exist. Create it? [y/N] ",
+        path.display()
+    );
+    let _ = std::io::stderr().flush();
+
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn context_with_dir(pdb_dir: PathBuf) -> AppContext {
+        AppContext {
+            config: Config::default(),
+            pdb_dir_source: SourcedValue::with_default(pdb_dir.clone()),
+            pdb_dir,
+            assume_yes: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_pdb_dir_from_nearest_layer() {
+        let layer = |path: &str, pdb_dir: Option<&str>| {
+            let mut config = Config::default();
+            config.paths.pdb_dir = pdb_dir.map(PathBuf::from);
+            ConfigLayer {
+                path: PathBuf::from(path),
+                config,
+            }
+        };
+
+        let layers = [
+            layer("/home/user/.config/pdb-sync/config.toml", Some("/data/pdb")),
+            layer("/work/project/.pdb-sync.toml", Some("/work/project/pdb")),
+        ];
+        let resolved = resolve_pdb_dir(&layers);
+        assert_eq!(resolved.value, PathBuf::from("/work/project/pdb"));
+        assert_eq!(
+            resolved.file,
+            Some(PathBuf::from("/work/project/.pdb-sync.toml"))
+        );
+
+        // A project config without pdb_dir falls through to the global one
+        let layers = [
+            layer("/home/user/.config/pdb-sync/config.toml", Some("/data/pdb")),
+            layer("/work/project/.pdb-sync.toml", None),
+        ];
+        let resolved = resolve_pdb_dir(&layers);
+        assert_eq!(resolved.value, PathBuf::from("/data/pdb"));
+        assert_eq!(
+            resolved.origin(),
+            "config file /home/user/.config/pdb-sync/config.toml"
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_project_pdb_dir_from_nested_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir(root.join(".git")).unwrap();
+        std::fs::write(
+            root.join(crate::config::loader::PROJECT_CONFIG_FILE),
+            "[paths]\npdb_dir = \"data/pdb\"\n",
+        )
+        .unwrap();
+        let nested = root.join("analysis/notebooks");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let path = ConfigLoader::find_project_config(&nested).unwrap();
+        let layers = [ConfigLayer {
+            config: ConfigLoader::load_from(&path).unwrap(),
+            path,
+        }];
+        assert_eq!(resolve_pdb_dir(&layers).value, root.join("data/pdb"));
+    }
+
+    #[test]
+    fn test_with_overrides_records_cli_source() {
+        let ctx = context_with_dir(PathBuf::from("/data/pdb"))
+            .with_overrides(Some(PathBuf::from("/tmp/pdb")));
+        assert_eq!(ctx.pdb_dir, PathBuf::from("/tmp/pdb"));
+        assert_eq!(ctx.pdb_dir_source.source, crate::config::FlagSource::CliArg);
+    }
+
+    #[test]
+    fn test_ensure_pdb_dir_creates_with_yes() {
+        let temp_dir = TempDir::new().unwrap();
+        let pdb_dir = temp_dir.path().join("new/pdb");
+        let ctx = context_with_dir(pdb_dir.clone()).with_assume_yes(true);
+
+        ctx.ensure_pdb_dir().unwrap();
+        assert!(pdb_dir.is_dir());
+    }
+
+    #[test]
+    fn test_ensure_pdb_dir_declined() {
+        let temp_dir = TempDir::new().unwrap();
+        let pdb_dir = temp_dir.path().join("pdb");
+        let ctx = context_with_dir(pdb_dir.clone());
+
+        let result = ctx.ensure_pdb_dir_with(|_| false);
+        assert!(matches!(result, Err(PdbSyncError::Path(_))));
+        assert!(!pdb_dir.exists());
+    }
+
+    #[test]
+    fn test_ensure_pdb_dir_not_a_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("pdb");
+        std::fs::write(&file, "").unwrap();
+        let ctx = context_with_dir(file).with_assume_yes(true);
+
+        let result = ctx.ensure_pdb_dir();
+        assert!(matches!(result, Err(PdbSyncError::Path(msg)) if msg.contains("not a directory")));
+    }
+
+    #[test]
+    fn test_ensure_pdb_dir_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        let ctx = context_with_dir(temp_dir.path().to_path_buf());
+        ctx.ensure_pdb_dir_with(|_| panic!("should not prompt"))
+            .unwrap();
+    }
 }
@@ -26,6 +26,9 @@ pub enum DataType {
     NmrRestraints,
     /// Obsolete entries (structures/obsolete)
     Obsolete,
+    /// Chemical Component Dictionary (monomers/components.cif.gz)
+    #[value(alias = "ccd", alias = "components")]
+    ChemicalComponents,
 }
 
 /// Directory layout options for PDB archive.
@@ -71,6 +74,7 @@ impl DataType {
             (DataType::NmrRestraints, Layout::All) => "structures/all/nmr_restraints",
             (DataType::Obsolete, Layout::Divided) => "structures/obsolete",
             (DataType::Obsolete, Layout::All) => "structures/obsolete",
+            (DataType::ChemicalComponents, _) => "monomers",
         }
     }
 
@@ -80,7 +84,9 @@ impl DataType {
     /// * `pdb_id` - The PDB ID (lowercase, 4 characters)
     ///
     /// # Returns
-    /// Filename pattern (may include wildcards for assemblies/biounit)
+    /// Filename pattern (may include wildcards for assemblies/biounit).
+    /// The Chemical Component Dictionary is a single file, so `pdb_id` is
+    /// ignored for it.
     pub fn filename_pattern(&self, pdb_id: &str) -> String {
         match self {
             DataType::Structures => format!("{}.cif.gz", pdb_id),
@@ -90,6 +96,7 @@ impl DataType {
             DataType::NmrChemicalShifts => format!("{}_cs.str.gz", pdb_id),
             DataType::NmrRestraints => format!("{}_mr.str.gz", pdb_id),
             DataType::Obsolete => format!("{}.cif.gz", pdb_id),
+            DataType::ChemicalComponents => "components.cif.gz".to_string(),
         }
     }
 
@@ -103,6 +110,7 @@ impl DataType {
             DataType::NmrChemicalShifts => "NMR chemical shifts",
             DataType::NmrRestraints => "NMR restraints",
             DataType::Obsolete => "Obsolete entries",
+            DataType::ChemicalComponents => "Chemical Component Dictionary (CCD)",
         }
     }
 
@@ -116,6 +124,7 @@ impl DataType {
             DataType::NmrChemicalShifts,
             DataType::NmrRestraints,
             DataType::Obsolete,
+            DataType::ChemicalComponents,
         ]
     }
 }
@@ -130,6 +139,7 @@ impl std::fmt::Display for DataType {
             DataType::NmrChemicalShifts => write!(f, "nmr-chemical-shifts"),
             DataType::NmrRestraints => write!(f, "nmr-restraints"),
             DataType::Obsolete => write!(f, "obsolete"),
+            DataType::ChemicalComponents => write!(f, "chemical-components"),
         }
     }
 }
@@ -203,6 +213,22 @@ mod tests {
         assert!(DataType::Biounit.filename_pattern("1abc").contains('*'));
     }
 
+    #[test]
+    fn test_chemical_components() {
+        let ccd = DataType::ChemicalComponents;
+        assert_eq!(ccd.rsync_subpath(Layout::Divided), "monomers");
+        assert_eq!(ccd.rsync_subpath(Layout::All), "monomers");
+        assert_eq!(ccd.filename_pattern("1abc"), "components.cif.gz");
+        assert_eq!(ccd.to_string(), "chemical-components");
+        assert_eq!(
+            serde_json::to_string(&ccd).unwrap(),
+            "\"chemical-components\""
+        );
+
+        use clap::ValueEnum;
+        assert_eq!(DataType::from_str("ccd", true).unwrap(), ccd);
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(DataType::Structures.to_string(), "structures");
@@ -219,7 +245,7 @@ mod tests {
     #[test]
     fn test_all_data_types() {
         let all = DataType::all();
-        assert_eq!(all.len(), 7);
+        assert_eq!(all.len(), 8);
         assert!(all.contains(&DataType::Structures));
         assert!(all.contains(&DataType::Obsolete));
     }
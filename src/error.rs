@@ -1,4 +1,17 @@
 //! Error types for pdb-sync.
+//!
+//! The CLI exits with a code chosen by [`PdbSyncError::exit_code`]:
+//!
+//! | Code | Category                                                    |
+//! |------|-------------------------------------------------------------|
+//! | 0    | Success                                                     |
+//! | 1    | Other failures (I/O, rsync, conversion, ...)                |
+//! | 2    | Invalid input or validation failure (also clap usage errors) |
+//! | 3    | Configuration error                                         |
+//! | 4    | Not found (entries, external tools)                         |
+//! | 5    | Network error, including transient rsync connection failures |
+//!
+//! `env doctor` failures keep the code reported by the doctor itself.
 
 use std::path::PathBuf;
 use thiserror::Error;
@@ -102,6 +115,10 @@ pub enum PdbSyncError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// Configuration parsed but failed validation.
+    #[error("Validation failed: {0}")]
+    Validation(String),
+
     /// Checksum mismatch with file information.
     #[allow(dead_code)]
     #[error(
@@ -201,6 +218,32 @@ impl PdbSyncError {
         }
     }
 
+    /// Process exit code for this error's category (see the module docs).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            PdbSyncError::InvalidPdbId { .. }
+            | PdbSyncError::InvalidInput(_)
+            | PdbSyncError::Validation(_)
+            | PdbSyncError::InvalidInterval(_)
+            | PdbSyncError::ChecksumMismatch { .. } => 2,
+            PdbSyncError::Config { .. }
+            | PdbSyncError::TomlParse(_)
+            | PdbSyncError::TomlSerialize(_)
+            | PdbSyncError::UnknownMirror(_) => 3,
+            PdbSyncError::NotFound { .. }
+            | PdbSyncError::EntriesNotFound(..)
+            | PdbSyncError::ToolNotFound(_)
+            | PdbSyncError::Aria2cNotFound => 4,
+            PdbSyncError::Network { .. }
+            | PdbSyncError::Download { .. }
+            | PdbSyncError::ChecksumFetch(_)
+            | PdbSyncError::SearchApi(_) => 5,
+            PdbSyncError::Rsync { .. } if self.is_retriable() => 5,
+            PdbSyncError::DoctorFailed { exit_code } => *exit_code,
+            _ => 1,
+        }
+    }
+
     /// Get the PDB ID associated with this error, if any.
     ///
     /// Returns the PDB ID if the error is related to a specific entry.
@@ -322,6 +365,56 @@ mod tests {
         assert_eq!(err.url(), Some("https://example.com/1abc.cif.gz"));
     }
 
+    #[test]
+    fn test_exit_code_categories() {
+        assert_eq!(PdbSyncError::InvalidInput("bad".to_string()).exit_code(), 2);
+        assert_eq!(PdbSyncError::Validation("bad".to_string()).exit_code(), 2);
+        assert_eq!(
+            PdbSyncError::Config {
+                message: "bad".to_string(),
+                key: None,
+                source: None,
+            }
+            .exit_code(),
+            3
+        );
+        assert_eq!(
+            PdbSyncError::NotFound {
+                pdb_id: "1abc".to_string(),
+                mirror: None,
+                searched_urls: vec![],
+            }
+            .exit_code(),
+            4
+        );
+        assert_eq!(
+            PdbSyncError::Network {
+                url: "https://example.com".to_string(),
+                message: "timeout".to_string(),
+                is_retriable: true,
+            }
+            .exit_code(),
+            5
+        );
+        assert_eq!(
+            PdbSyncError::Io(std::io::Error::other("disk full")).exit_code(),
+            1
+        );
+        assert_eq!(PdbSyncError::DoctorFailed { exit_code: 2 }.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_exit_code_rsync() {
+        let rsync = |code| PdbSyncError::Rsync {
+            command: "rsync -avz src/ dest/".to_string(),
+            exit_code: Some(code),
+            stderr: None,
+        };
+        // Socket I/O errors are network failures; partial transfers are not
+        assert_eq!(rsync(10).exit_code(), 5);
+        assert_eq!(rsync(23).exit_code(), 1);
+    }
+
     #[test]
     fn test_checksum_mismatch_display() {
         let err = PdbSyncError::ChecksumMismatch {
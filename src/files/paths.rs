@@ -19,6 +19,18 @@ pub enum FileFormat {
 }
 
 impl FileFormat {
+    /// Get all supported file formats.
+    pub fn all() -> &'static [FileFormat] {
+        &[
+            FileFormat::Pdb,
+            FileFormat::Mmcif,
+            FileFormat::Bcif,
+            FileFormat::PdbGz,
+            FileFormat::CifGz,
+            FileFormat::BcifGz,
+        ]
+    }
+
     /// Get the base format (uncompressed version)
     pub fn base_format(&self) -> FileFormat {
         match self {
@@ -27,6 +39,38 @@ impl FileFormat {
             FileFormat::Bcif | FileFormat::BcifGz => FileFormat::Bcif,
         }
     }
+
+    /// Get the canonical file extension (without leading dot)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Pdb => "pdb",
+            FileFormat::Mmcif => "cif",
+            FileFormat::Bcif => "bcif",
+            FileFormat::PdbGz => "ent.gz",
+            FileFormat::CifGz => "cif.gz",
+            FileFormat::BcifGz => "bcif.gz",
+        }
+    }
+
+    /// Check if this format is gzip-compressed
+    pub fn is_compressed(&self) -> bool {
+        matches!(
+            self,
+            FileFormat::PdbGz | FileFormat::CifGz | FileFormat::BcifGz
+        )
+    }
+
+    /// Get a human-readable description of this format
+    pub fn description(&self) -> &'static str {
+        match self {
+            FileFormat::Pdb => "Legacy PDB format",
+            FileFormat::Mmcif => "mmCIF format",
+            FileFormat::Bcif => "BinaryCIF format",
+            FileFormat::PdbGz => "Compressed legacy PDB format",
+            FileFormat::CifGz => "Compressed mmCIF format",
+            FileFormat::BcifGz => "Compressed BinaryCIF format",
+        }
+    }
 }
 
 impl std::fmt::Display for FileFormat {
@@ -69,6 +113,30 @@ mod tests {
         assert_eq!("pdb".parse::<FileFormat>().unwrap(), FileFormat::Pdb);
     }
 
+    #[test]
+    fn test_all_formats() {
+        let all = FileFormat::all();
+        assert_eq!(all.len(), 6);
+        for format in all {
+            // Every variant must be listed exactly once
+            assert_eq!(all.iter().filter(|f| *f == format).count(), 1);
+            // Display name must round-trip through FromStr
+            assert_eq!(format.to_string().parse::<FileFormat>().unwrap(), *format);
+        }
+    }
+
+    #[test]
+    fn test_format_metadata_consistency() {
+        for format in FileFormat::all() {
+            assert_eq!(format.is_compressed(), format.extension().ends_with(".gz"));
+            assert!(!format.base_format().is_compressed());
+            assert_eq!(format.base_format().base_format(), format.base_format());
+        }
+        assert_eq!(FileFormat::CifGz.extension(), "cif.gz");
+        assert_eq!(FileFormat::PdbGz.extension(), "ent.gz");
+        assert!(!FileFormat::Mmcif.is_compressed());
+    }
+
     #[test]
     fn test_format_alias_cif() {
         // "cif" should be an alias for "mmcif"
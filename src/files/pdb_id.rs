@@ -75,19 +75,54 @@ impl PdbId {
 
     /// Returns the middle two characters used for directory partitioning.
     ///
+    /// This is the bucket used by the wwPDB "divided" layout:
+    ///
     /// - Classic IDs: characters at positions 1-2 (e.g., "1abc" → "ab")
-    /// - Extended IDs: characters at positions 6-7 (e.g., "pdb_00001abc" → "01")
+    /// - Extended IDs: characters at positions 6-7 (e.g., "pdb_00001abc" → "00")
+    ///
+    /// Positions are 0-indexed. Three-character legacy IDs are not valid
+    /// [`PdbId`]s and are rejected by [`PdbId::new`]. If a variant is
+    /// constructed directly with a malformed value, an empty string is
+    /// returned instead of panicking.
     pub fn middle_chars(&self) -> &str {
-        match self {
-            PdbId::Classic(id) => &id[1..3],
-            PdbId::Extended(id) => &id[6..8],
-        }
+        let (id, range) = match self {
+            PdbId::Classic(id) => (id, 1..3),
+            PdbId::Extended(id) => (id, 6..8),
+        };
+        id.get(range).unwrap_or("")
     }
 
     /// Returns true if this is a classic 4-character PDB ID.
     pub fn is_classic(&self) -> bool {
         matches!(self, PdbId::Classic(_))
     }
+
+    /// Returns the classic 4-character form of this ID, if one exists.
+    ///
+    /// Extended IDs map to classic IDs only when they are zero-padded
+    /// classic IDs (e.g., "pdb_00001abc" → "1abc"). IDs allocated after the
+    /// classic space is exhausted (e.g., "pdb_10001abc") have no classic form.
+    pub fn to_classic(&self) -> Option<PdbId> {
+        match self {
+            PdbId::Classic(_) => Some(self.clone()),
+            PdbId::Extended(id) => {
+                let classic = id.strip_prefix("pdb_0000")?;
+                CLASSIC_REGEX
+                    .is_match(classic)
+                    .then(|| PdbId::Classic(classic.to_string()))
+            }
+        }
+    }
+
+    /// Returns the extended 12-character form of this ID.
+    ///
+    /// Every classic ID has an extended form (e.g., "1abc" → "pdb_00001abc").
+    pub fn to_extended(&self) -> PdbId {
+        match self {
+            PdbId::Classic(id) => PdbId::Extended(format!("pdb_0000{}", id)),
+            PdbId::Extended(_) => self.clone(),
+        }
+    }
 }
 
 impl fmt::Display for PdbId {
@@ -182,6 +217,34 @@ mod tests {
         assert_eq!(id3.middle_chars(), "cd");
     }
 
+    #[test]
+    fn test_classic_extended_conversion() {
+        let classic = PdbId::new("1abc").unwrap();
+        let extended = PdbId::new("pdb_00001abc").unwrap();
+
+        assert_eq!(classic.to_extended(), extended);
+        assert_eq!(extended.to_classic(), Some(classic.clone()));
+        assert_eq!(classic.to_classic(), Some(classic.clone()));
+        assert_eq!(extended.to_extended(), extended);
+
+        // Round trips
+        assert_eq!(classic.to_extended().to_classic(), Some(classic));
+        assert_eq!(extended.to_classic().unwrap().to_extended(), extended);
+
+        assert_eq!(
+            PdbId::new("4hhb").unwrap().to_extended().as_str(),
+            "pdb_00004hhb"
+        );
+    }
+
+    #[test]
+    fn test_extended_without_classic_form() {
+        assert_eq!(PdbId::new("pdb_10001abc").unwrap().to_classic(), None);
+        assert_eq!(PdbId::new("pdb_12345678").unwrap().to_classic(), None);
+        // Zero-padded but not a valid classic ID (must start with a digit)
+        assert_eq!(PdbId::new("pdb_0000abcd").unwrap().to_classic(), None);
+    }
+
     #[test]
     fn test_extended_display() {
         let id = PdbId::new("pdb_00001abc").unwrap();
@@ -247,4 +310,49 @@ mod tests {
         let id2 = PdbId::new("\tpdb_00001abc\n").unwrap();
         assert_eq!(id2.as_str(), "pdb_00001abc");
     }
+
+    // === middle_chars matrix ===
+
+    #[test]
+    fn test_middle_chars_matrix() {
+        let cases = [
+            // Classic: positions 1-2
+            ("1abc", "ab"),
+            ("4hhb", "hh"),
+            ("1ABC", "ab"),
+            ("1111", "11"),
+            ("9zzz", "zz"),
+            ("0000", "00"),
+            ("2a0b", "a0"),
+            // Extended: positions 6-7
+            ("pdb_00001abc", "00"),
+            ("pdb_12345678", "34"),
+            ("pdb_abcdefgh", "cd"),
+            ("pdb_11111111", "11"),
+            ("pdb_zzzzzzzz", "zz"),
+            ("PDB_0000ABCD", "00"),
+            ("pdb_00ab1cde", "ab"),
+        ];
+
+        for (input, expected) in cases {
+            let id = PdbId::new(input).unwrap();
+            assert_eq!(id.middle_chars(), expected, "middle_chars({})", input);
+            assert_eq!(id.middle_chars().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_middle_chars_legacy_three_char_rejected() {
+        assert!(PdbId::new("1ab").is_err());
+        assert!(PdbId::new("abc").is_err());
+    }
+
+    #[test]
+    fn test_middle_chars_malformed_variant_does_not_panic() {
+        assert_eq!(PdbId::Classic("1a".to_string()).middle_chars(), "");
+        assert_eq!(PdbId::Classic(String::new()).middle_chars(), "");
+        assert_eq!(PdbId::Extended("pdb_0".to_string()).middle_chars(), "");
+        // Non-ASCII content must not split a character
+        assert_eq!(PdbId::Classic("1bé".to_string()).middle_chars(), "");
+    }
 }
@@ -7,3 +7,4 @@ pub mod utils;
 
 // Re-export commonly used types
 pub use data_types::{DataType, Layout};
+pub use files::PdbId;
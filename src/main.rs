@@ -11,10 +11,28 @@ mod sync;
 
 use cli::{parse_cli, SyncCommand};
 use context::AppContext;
+use error::PdbSyncError;
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() {
+    if let Err(e) = run().await {
+        report_error(&e);
+        std::process::exit(e.exit_code());
+    }
+}
+
+/// Print an error and its chain of causes to stderr.
+fn report_error(e: &PdbSyncError) {
+    eprintln!("Error: {}", e);
+    let mut source = std::error::Error::source(e);
+    while let Some(cause) = source {
+        eprintln!("  Caused by: {}", cause);
+        source = cause.source();
+    }
+}
+
+async fn run() -> error::Result<()> {
     let cli = parse_cli();
 
     // Initialize logging
@@ -29,25 +47,61 @@ async fn main() -> anyhow::Result<()> {
         .with_target(false)
         .init();
 
+    // Completion scripts only depend on the CLI definition, so generate them
+    // before any config is read; they must work with a missing or broken one
+    if let SyncCommand::Env(ref args) = cli.command {
+        if let cli::args::env::EnvCommand::Completions { shell } = args.command {
+            return cli::commands::env::run_completions(shell);
+        }
+    }
+
+    // An explicit config file (--config, then PDB_SYNC_CONFIG) must exist and
+    // parse. Config subcommands that create or check the file get it as their target.
+    let explicit_config = config::ConfigLoader::explicit_path(
+        cli.config.clone(),
+        std::env::var_os("PDB_SYNC_CONFIG"),
+    );
+    if let Some(ref path) = explicit_config {
+        if !cli.command.manages_config_file() {
+            config::ConfigLoader::set_path_override(path.clone())?;
+        }
+    }
+
     // Load context
-    let ctx = AppContext::new().await?.with_overrides(cli.pdb_dir.clone());
+    let ctx = AppContext::new()
+        .await?
+        .with_overrides(cli.pdb_dir.clone())
+        .with_assume_yes(cli.yes);
 
     // Dispatch to command
+    let dry_run = cli.dry_run;
     match cli.command {
-        SyncCommand::Sync(args) => {
+        SyncCommand::Sync(mut args) => {
+            args.dry_run = dry_run;
             cli::args::sync::run_sync(*args, ctx).await?;
         }
         SyncCommand::Env(args) => {
             cli::args::env::run_env(args, ctx)?;
         }
+        SyncCommand::Formats(args) => {
+            cli::args::formats::run_formats(args)?;
+        }
+        SyncCommand::Presets(args) => {
+            cli::args::presets::run_presets(args)?;
+        }
         SyncCommand::Config(args) => match args.command {
-            cli::args::config::ConfigCommand::Init(init_args) => {
+            cli::args::config::ConfigCommand::Init(mut init_args) => {
+                init_args.dry_run = dry_run;
+                init_args.config = init_args.config.or(explicit_config);
                 cli::args::config::run_init(init_args).await?;
             }
-            cli::args::config::ConfigCommand::Validate(validate_args) => {
+            cli::args::config::ConfigCommand::Validate(mut validate_args) => {
+                validate_args.config = validate_args.config.or(explicit_config);
                 cli::args::config::run_validate(validate_args).await?;
             }
-            cli::args::config::ConfigCommand::Migrate(migrate_args) => {
+            cli::args::config::ConfigCommand::Migrate(mut migrate_args) => {
+                migrate_args.dry_run = dry_run;
+                migrate_args.config = migrate_args.config.or(explicit_config);
                 cli::args::config::run_migrate(migrate_args).await?;
             }
             cli::args::config::ConfigCommand::Presets => {
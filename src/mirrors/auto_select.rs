@@ -6,18 +6,28 @@
 #![allow(dead_code)]
 
 use crate::mirrors::{Mirror, MirrorId};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 
 /// Thread-safe cache for mirror latencies.
 pub struct LatencyCache {
-    /// Map of mirror ID to (latency, timestamp)
-    cache: RwLock<HashMap<MirrorId, (Duration, Instant)>>,
+    /// Map of mirror ID to (latency, measurement time)
+    cache: RwLock<HashMap<MirrorId, (Duration, SystemTime)>>,
     /// Cache TTL
     ttl: Duration,
 }
 
+/// On-disk form of a cached latency measurement.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLatency {
+    latency_ms: u64,
+    /// Measurement time in seconds since the Unix epoch
+    measured_at: u64,
+}
+
 impl LatencyCache {
     /// Create a new latency cache with the given TTL.
     pub fn new(ttl: Duration) -> Self {
@@ -27,23 +37,75 @@ impl LatencyCache {
         }
     }
 
+    /// Load a latency cache from a file written by [`LatencyCache::save`].
+    ///
+    /// A missing or unreadable file yields an empty cache.
+    pub fn load(path: &Path, ttl: Duration) -> Self {
+        let entries: HashMap<String, CachedLatency> = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        let cache = entries
+            .into_iter()
+            .filter_map(|(id, entry)| {
+                let id = id.parse().ok()?;
+                let measured_at = UNIX_EPOCH + Duration::from_secs(entry.measured_at);
+                Some((id, (Duration::from_millis(entry.latency_ms), measured_at)))
+            })
+            .collect();
+
+        Self {
+            cache: RwLock::new(cache),
+            ttl,
+        }
+    }
+
+    /// Write all cached entries to a file.
+    pub async fn save(&self, path: &Path) -> std::io::Result<()> {
+        let entries: HashMap<String, CachedLatency> = self
+            .cache
+            .read()
+            .await
+            .iter()
+            .map(|(id, (latency, measured_at))| {
+                let entry = CachedLatency {
+                    latency_ms: latency.as_millis() as u64,
+                    measured_at: measured_at
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs(),
+                };
+                (id.to_string(), entry)
+            })
+            .collect();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&entries)?)
+    }
+
+    fn is_valid(&self, measured_at: SystemTime) -> bool {
+        measured_at
+            .elapsed()
+            .map(|age| age < self.ttl)
+            .unwrap_or(false)
+    }
+
     /// Get cached latency for a mirror if still valid.
     #[allow(dead_code)]
     pub async fn get(&self, mirror_id: MirrorId) -> Option<Duration> {
         let cache = self.cache.read().await;
-        cache.get(&mirror_id).and_then(|(latency, timestamp)| {
-            if timestamp.elapsed() < self.ttl {
-                Some(*latency)
-            } else {
-                None
-            }
-        })
+        cache
+            .get(&mirror_id)
+            .and_then(|&(latency, measured_at)| self.is_valid(measured_at).then_some(latency))
     }
 
     /// Store latency for a mirror.
     pub async fn set(&self, mirror_id: MirrorId, latency: Duration) {
         let mut cache = self.cache.write().await;
-        cache.insert(mirror_id, (latency, Instant::now()));
+        cache.insert(mirror_id, (latency, SystemTime::now()));
     }
 
     /// Get all cached entries that are still valid.
@@ -51,61 +113,97 @@ impl LatencyCache {
         let cache = self.cache.read().await;
         cache
             .iter()
-            .filter_map(|(id, (latency, timestamp))| {
-                if timestamp.elapsed() < self.ttl {
-                    Some((*id, *latency))
-                } else {
-                    None
-                }
+            .filter_map(|(id, &(latency, measured_at))| {
+                self.is_valid(measured_at).then_some((*id, latency))
             })
             .collect()
     }
+
+    /// Mirrors without a valid cached latency.
+    pub async fn expired(&self) -> Vec<MirrorId> {
+        let valid = self.get_all_valid().await;
+        MirrorId::all()
+            .iter()
+            .copied()
+            .filter(|id| !valid.contains_key(id))
+            .collect()
+    }
+}
+
+/// Path of the persisted latency cache (`<cache dir>/pdb-sync/mirror-latency.json`).
+pub fn latency_cache_path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "pdb-sync")
+        .map(|dirs| dirs.cache_dir().join("mirror-latency.json"))
 }
 
 /// Global latency cache.
 ///
-/// Note: The cache is lazily initialized on first use. The TTL is only set during
-/// initialization, so subsequent calls with different TTL values will use the
-/// originally configured TTL. This is by design for CLI usage where configuration
-/// is typically constant throughout a session.
+/// Note: The cache is lazily initialized on first use, loading any persisted
+/// measurements. The TTL is only set during initialization, so subsequent
+/// calls with different TTL values will use the originally configured TTL.
+/// This is by design for CLI usage where configuration is typically constant
+/// throughout a session.
 static LATENCY_CACHE: std::sync::OnceLock<LatencyCache> = std::sync::OnceLock::new();
 
 fn get_cache(ttl: Duration) -> &'static LatencyCache {
-    LATENCY_CACHE.get_or_init(|| LatencyCache::new(ttl))
+    LATENCY_CACHE.get_or_init(|| match latency_cache_path() {
+        Some(path) => LatencyCache::load(&path, ttl),
+        None => LatencyCache::new(ttl),
+    })
 }
 
 /// Select the best mirror based on latency testing.
 ///
+/// Latencies are cached on disk; only mirrors whose cached measurement is
+/// older than `cache_ttl` are probed again, unless `refresh` is set.
+///
 /// # Arguments
 /// * `preferred_region` - Optional preferred region (e.g., "us", "jp", "europe")
 /// * `cache_ttl` - TTL for latency cache
+/// * `refresh` - Probe all mirrors, ignoring cached latencies
 ///
 /// # Returns
 /// The mirror ID with the lowest latency (or preferred region within 2x tolerance)
-pub async fn select_best_mirror(preferred_region: Option<&str>, cache_ttl: Duration) -> MirrorId {
+pub async fn select_best_mirror(
+    preferred_region: Option<&str>,
+    cache_ttl: Duration,
+    refresh: bool,
+) -> MirrorId {
     let cache = get_cache(cache_ttl);
 
-    // Check if we have valid cached results for all mirrors
-    let cached = cache.get_all_valid().await;
-    let results = if cached.len() == MirrorId::all().len() {
-        cached
+    let to_probe = if refresh {
+        MirrorId::all().to_vec()
     } else {
-        // Test all mirrors and cache results
-        let fresh = test_all_mirrors().await;
-        for (&id, &latency) in &fresh {
+        cache.expired().await
+    };
+
+    if !to_probe.is_empty() {
+        // Test stale mirrors and cache results
+        for (id, latency) in test_mirrors(&to_probe).await {
             cache.set(id, latency).await;
         }
-        fresh
-    };
+        if let Some(path) = latency_cache_path() {
+            if let Err(e) = cache.save(&path).await {
+                tracing::debug!("Failed to save latency cache: {}", e);
+            }
+        }
+    }
 
-    find_best_from_results(&results, preferred_region)
+    find_best_from_results(&cache.get_all_valid().await, preferred_region)
 }
 
 /// Test latency to all mirrors.
 ///
 /// Returns a map of mirror ID to latency (only successful tests).
 pub async fn test_all_mirrors() -> HashMap<MirrorId, Duration> {
-    let handles: Vec<_> = MirrorId::all()
+    test_mirrors(MirrorId::all()).await
+}
+
+/// Test latency to the given mirrors.
+///
+/// Returns a map of mirror ID to latency (only successful tests).
+pub async fn test_mirrors(ids: &[MirrorId]) -> HashMap<MirrorId, Duration> {
+    let handles: Vec<_> = ids
         .iter()
         .map(|&id| {
             tokio::spawn(async move {
@@ -348,4 +446,50 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(20)).await;
         assert!(cache.get(MirrorId::Rcsb).await.is_none());
     }
+
+    #[tokio::test]
+    async fn test_latency_cache_persistence() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("cache/mirror-latency.json");
+
+        let cache = LatencyCache::new(Duration::from_secs(60));
+        cache.set(MirrorId::Pdbj, Duration::from_millis(42)).await;
+        cache.save(&path).await.unwrap();
+
+        let loaded = LatencyCache::load(&path, Duration::from_secs(60));
+        assert_eq!(
+            loaded.get(MirrorId::Pdbj).await,
+            Some(Duration::from_millis(42))
+        );
+
+        // Only mirrors without a valid cached value need probing
+        let expired = loaded.expired().await;
+        assert!(!expired.contains(&MirrorId::Pdbj));
+        assert_eq!(expired.len(), MirrorId::all().len() - 1);
+
+        // Preferred region tolerance applies to cached values
+        loaded.set(MirrorId::Rcsb, Duration::from_millis(80)).await;
+        let best = find_best_from_results(&loaded.get_all_valid().await, Some("us"));
+        assert_eq!(best, MirrorId::Rcsb);
+    }
+
+    #[tokio::test]
+    async fn test_latency_cache_load_expired_or_missing() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("mirror-latency.json");
+
+        // Missing file yields an empty cache
+        let cache = LatencyCache::load(&path, Duration::from_secs(60));
+        assert!(cache.get_all_valid().await.is_empty());
+
+        // Entries older than the TTL are loaded but not used
+        std::fs::write(
+            &path,
+            r#"{"rcsb": {"latency_ms": 100, "measured_at": 1000}, "bogus": {"latency_ms": 1, "measured_at": 1000}}"#,
+        )
+        .unwrap();
+        let cache = LatencyCache::load(&path, Duration::from_secs(60));
+        assert!(cache.get(MirrorId::Rcsb).await.is_none());
+        assert_eq!(cache.expired().await.len(), MirrorId::all().len());
+    }
 }
@@ -19,7 +19,6 @@ pub enum MirrorId {
 }
 
 impl MirrorId {
-    #[allow(dead_code)]
     pub fn all() -> &'static [MirrorId] {
         &[
             MirrorId::Rcsb,
@@ -105,6 +104,16 @@ impl Mirror {
         self.rsync_port.map(|p| format!("--port={}", p))
     }
 
+    /// Check whether this mirror serves files in the given format.
+    ///
+    /// Only RCSB serves BinaryCIF; the other mirrors fall back to mmCIF.
+    pub fn serves_format(&self, format: FileFormat) -> bool {
+        match format.base_format() {
+            FileFormat::Bcif => self.id == MirrorId::Rcsb,
+            _ => true,
+        }
+    }
+
     /// Build HTTPS URL for structure file downloads.
     ///
     /// This is the canonical URL construction for structure files across all mirrors.
@@ -281,6 +290,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serves_format() {
+        for &id in MirrorId::all() {
+            let mirror = Mirror::get(id);
+            assert!(mirror.serves_format(FileFormat::Mmcif));
+            assert!(mirror.serves_format(FileFormat::PdbGz));
+            assert_eq!(
+                mirror.serves_format(FileFormat::BcifGz),
+                id == MirrorId::Rcsb
+            );
+        }
+    }
+
     #[test]
     fn test_build_structure_url_rcsb_classic() {
         let mirror = Mirror::get(MirrorId::Rcsb);
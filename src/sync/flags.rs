@@ -2,6 +2,7 @@
 
 use crate::error::{PdbSyncError, Result};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use tokio::process::Command;
 
 /// Common rsync flags shared between configuration and CLI arguments.
@@ -172,6 +173,14 @@ impl RsyncFlags {
             validate_chmod_string(chmod)?;
         }
 
+        // Validate filter files exist before rsync fails with a cryptic error
+        if let Some(ref file) = self.include_from {
+            validate_filter_file("include_from", file)?;
+        }
+        if let Some(ref file) = self.exclude_from {
+            validate_filter_file("exclude_from", file)?;
+        }
+
         Ok(())
     }
 
@@ -524,11 +533,11 @@ impl RsyncFlags {
         }
 
         if let Some(ref file) = self.include_from {
-            cmd.arg(format!("--include-from={}", file));
+            cmd.arg(format!("--include-from={}", expand_home(file).display()));
         }
 
         if let Some(ref file) = self.exclude_from {
-            cmd.arg(format!("--exclude-from={}", file));
+            cmd.arg(format!("--exclude-from={}", expand_home(file).display()));
         }
 
         // Verbosity
@@ -640,11 +649,11 @@ impl RsyncFlags {
         }
 
         if let Some(ref file) = self.include_from {
-            args.push(format!("--include-from={}", file));
+            args.push(format!("--include-from={}", expand_home(file).display()));
         }
 
         if let Some(ref file) = self.exclude_from {
-            args.push(format!("--exclude-from={}", file));
+            args.push(format!("--exclude-from={}", expand_home(file).display()));
         }
 
         // Verbosity
@@ -664,6 +673,40 @@ impl RsyncFlags {
     }
 }
 
+/// Expand a leading `~` to the user's home directory.
+fn expand_home(path: &str) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some("") => "",
+        Some(rest) if rest.starts_with('/') => &rest[1..],
+        _ => return PathBuf::from(path),
+    };
+    match directories::UserDirs::new() {
+        Some(dirs) => dirs.home_dir().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Validate that a filter file (--include-from, --exclude-from) is a readable file.
+fn validate_filter_file(option: &str, file: &str) -> Result<()> {
+    let path = expand_home(file);
+    if !path.is_file() {
+        return Err(PdbSyncError::InvalidInput(format!(
+            "{} file not found: {}",
+            option,
+            path.display()
+        )));
+    }
+    std::fs::File::open(&path).map_err(|e| {
+        PdbSyncError::InvalidInput(format!(
+            "{} file is not readable: {} ({})",
+            option,
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(())
+}
+
 /// Validate a size string for rsync (--max-size, --min-size).
 ///
 /// Valid formats: "100", "100K", "100M", "1G", etc.
@@ -856,6 +899,49 @@ mod tests {
         assert!(flags.validate().is_ok());
     }
 
+    #[test]
+    fn test_validate_filter_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let filter = temp_dir.path().join("exclude.txt");
+        std::fs::write(&filter, "*.tmp\n").unwrap();
+
+        let flags = RsyncFlags {
+            exclude_from: Some(filter.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert!(flags.validate().is_ok());
+
+        let missing = temp_dir.path().join("missing.txt");
+        let flags = RsyncFlags {
+            include_from: Some(missing.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        let err = flags.validate().unwrap_err().to_string();
+        assert!(err.contains("include_from"));
+        assert!(err.contains("missing.txt"));
+
+        // Directories are not filter files
+        let flags = RsyncFlags {
+            exclude_from: Some(temp_dir.path().to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert!(flags.validate().is_err());
+    }
+
+    #[test]
+    fn test_expand_home() {
+        assert_eq!(expand_home("/etc/filters"), PathBuf::from("/etc/filters"));
+        assert_eq!(expand_home("filters.txt"), PathBuf::from("filters.txt"));
+        assert_eq!(expand_home("~user/filters"), PathBuf::from("~user/filters"));
+        if let Some(dirs) = directories::UserDirs::new() {
+            assert_eq!(
+                expand_home("~/filters.txt"),
+                dirs.home_dir().join("filters.txt")
+            );
+            assert_eq!(expand_home("~"), dirs.home_dir());
+        }
+    }
+
     #[test]
     fn test_validate_verbose_quiet_conflict() {
         let flags = RsyncFlags {
@@ -0,0 +1,187 @@
+//! Remote file listing via `rsync --list-only`.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use regex::Regex;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// A single entry from `rsync --list-only` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteEntry {
+    /// Path relative to the listed source directory.
+    pub name: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub mtime: NaiveDateTime,
+}
+
+/// Parse `rsync --list-only` output into remote entries.
+///
+/// Lines that don't look like listing entries (daemon MOTD, warnings)
+/// are skipped, as is the `.` entry for the source directory itself.
+pub fn parse_list_only(output: &str) -> Vec<RemoteEntry> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(r"^([dlcbps-])\S*\s+([\d,.]+)\s+(\d{4}/\d{2}/\d{2} \d{2}:\d{2}:\d{2})\s+(.+)$")
+            .unwrap()
+    });
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let caps = re.captures(line.trim_end())?;
+            let kind = &caps[1];
+            let size = caps[2].replace([',', '.'], "").parse().ok()?;
+            let mtime = NaiveDateTime::parse_from_str(&caps[3], "%Y/%m/%d %H:%M:%S").ok()?;
+
+            let mut name = caps[4].to_string();
+            if kind == "l" {
+                // Symlinks are listed as "name -> target"
+                if let Some(idx) = name.find(" -> ") {
+                    name.truncate(idx);
+                }
+            }
+            if name == "." {
+                return None;
+            }
+
+            Some(RemoteEntry {
+                name,
+                is_dir: kind == "d",
+                size,
+                mtime,
+            })
+        })
+        .collect()
+}
+
+/// Select the non-directory entries modified at or before `cutoff`.
+pub fn files_not_newer_than(entries: &[RemoteEntry], cutoff: NaiveDateTime) -> Vec<&str> {
+    entries
+        .iter()
+        .filter(|e| !e.is_dir && e.mtime <= cutoff)
+        .map(|e| e.name.as_str())
+        .collect()
+}
+
+/// Keep the relative paths in `files` that do not exist under `dest`.
+///
+/// Anything already present at the path, including a dangling symlink,
+/// counts as existing.
+pub fn files_missing_from<'a>(files: Vec<&'a str>, dest: &Path) -> Vec<&'a str> {
+    files
+        .into_iter()
+        .filter(|f| dest.join(f).symlink_metadata().is_err())
+        .collect()
+}
+
+/// Parse a snapshot cutoff given as `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM:SS`.
+///
+/// A bare date means the end of that day, so files modified on the
+/// cutoff date are included.
+pub fn parse_cutoff(s: &str) -> std::result::Result<NaiveDateTime, String> {
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(dt);
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+        return Ok(dt);
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(23, 59, 59))
+        .ok_or_else(|| {
+            format!(
+                "Invalid date '{}' (expected YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)",
+                s
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_parse_list_only() {
+        let output = "\
+Welcome to the rsync server
+drwxr-xr-x          4,096 2024/01/15 10:30:00 .
+drwxr-xr-x          4,096 2024/01/15 10:30:00 ab
+-rw-r--r--         12,345 2024/01/10 08:00:00 ab/pdb1abc.ent.gz
+-rw-r--r--            987 2024/02/01 12:00:00 ab/pdb2abc.ent.gz
+lrwxrwxrwx             11 2023/12/31 23:59:59 latest -> ab/pdb1abc.ent.gz
+";
+        let entries = parse_list_only(output);
+        assert_eq!(entries.len(), 4);
+
+        assert_eq!(entries[0].name, "ab");
+        assert!(entries[0].is_dir);
+
+        assert_eq!(entries[1].name, "ab/pdb1abc.ent.gz");
+        assert_eq!(entries[1].size, 12345);
+        assert_eq!(entries[1].mtime, dt("2024-01-10 08:00:00"));
+        assert!(!entries[1].is_dir);
+
+        assert_eq!(entries[2].size, 987);
+        assert_eq!(entries[3].name, "latest");
+    }
+
+    #[test]
+    fn test_parse_list_only_filenames_with_spaces() {
+        let output = "-rw-r--r--            100 2024/01/01 00:00:00 my file.txt\n";
+        let entries = parse_list_only(output);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "my file.txt");
+    }
+
+    #[test]
+    fn test_files_not_newer_than() {
+        let output = "\
+drwxr-xr-x          4,096 2024/01/15 10:30:00 ab
+-rw-r--r--         12,345 2024/01/10 08:00:00 ab/pdb1abc.ent.gz
+-rw-r--r--            987 2024/02/01 12:00:00 ab/pdb2abc.ent.gz
+";
+        let entries = parse_list_only(output);
+        let selected = files_not_newer_than(&entries, dt("2024-01-31 23:59:59"));
+        assert_eq!(selected, vec!["ab/pdb1abc.ent.gz"]);
+
+        // Cutoff is inclusive
+        let selected = files_not_newer_than(&entries, dt("2024-02-01 12:00:00"));
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn test_files_missing_from() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("ab")).unwrap();
+        std::fs::write(temp_dir.path().join("ab/pdb1abc.ent.gz"), b"").unwrap();
+
+        let missing = files_missing_from(
+            vec![
+                "ab/pdb1abc.ent.gz",
+                "ab/pdb2abc.ent.gz",
+                "cd/pdb1acd.ent.gz",
+            ],
+            temp_dir.path(),
+        );
+        assert_eq!(missing, vec!["ab/pdb2abc.ent.gz", "cd/pdb1acd.ent.gz"]);
+    }
+
+    #[test]
+    fn test_parse_cutoff() {
+        assert_eq!(
+            parse_cutoff("2024-01-15").unwrap(),
+            dt("2024-01-15 23:59:59")
+        );
+        assert_eq!(
+            parse_cutoff("2024-01-15T08:30:00").unwrap(),
+            dt("2024-01-15 08:30:00")
+        );
+        assert!(parse_cutoff("2024-13-01").is_err());
+        assert!(parse_cutoff("yesterday").is_err());
+    }
+}
@@ -1,8 +1,12 @@
 pub mod flags;
+pub mod listing;
+pub mod mtime;
 pub mod plan;
 pub mod presets;
+pub mod progress;
+pub mod split;
 pub mod validator;
 
 pub use flags::{RsyncFlagOverrides, RsyncFlags};
-pub use plan::{parse_rsync_stats, SyncPlan};
+pub use plan::{parse_rsync_stats, parse_sync_diff, SyncDiff, SyncPlan};
 pub use presets::{get_rsync_preset, list_rsync_presets, RsyncPreset};
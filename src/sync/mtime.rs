@@ -0,0 +1,182 @@
+//! Destination filesystem timestamp granularity detection.
+//!
+//! Filesystems such as FAT/exFAT store modification times with 2-second
+//! granularity, which makes rsync re-transfer every file unless
+//! `--modify-window` is set. This module probes the destination and picks
+//! a suitable window.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Writes a modification time to the filesystem and reads back what was stored.
+pub trait MtimeProbe {
+    fn round_trip(&self, requested: SystemTime) -> io::Result<SystemTime>;
+}
+
+/// Probe that sets the mtime of a temporary file in a directory.
+pub struct DirProbe {
+    dir: PathBuf,
+}
+
+impl DirProbe {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl MtimeProbe for DirProbe {
+    fn round_trip(&self, requested: SystemTime) -> io::Result<SystemTime> {
+        let path = self
+            .dir
+            .join(format!(".pdb-sync-mtime-probe-{}", std::process::id()));
+
+        let result = (|| {
+            let file = std::fs::File::create(&path)?;
+            file.set_modified(requested)?;
+            drop(file);
+            std::fs::metadata(&path)?.modified()
+        })();
+
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+}
+
+/// Detect the timestamp granularity of the filesystem behind `probe`.
+///
+/// An odd second with a sub-second fraction is written; losing the fraction
+/// means 1-second granularity, and landing on an even second means 2-second
+/// (FAT-style) granularity.
+pub fn detect_granularity(probe: &impl MtimeProbe) -> io::Result<Duration> {
+    // 2021-01-01T00:00:01.123456789Z: odd second, non-zero nanoseconds
+    let requested = UNIX_EPOCH + Duration::new(1_609_459_201, 123_456_789);
+    let stored = probe.round_trip(requested)?;
+
+    let stored_since = stored.duration_since(UNIX_EPOCH).unwrap_or_default();
+    if stored_since.subsec_nanos() == 0 && stored != requested {
+        // Fraction dropped: an even second means the odd one was not representable
+        if stored_since.as_secs() % 2 == 0 {
+            return Ok(Duration::from_secs(2));
+        }
+        return Ok(Duration::from_secs(1));
+    }
+
+    // Sub-second granularity (e.g., 100ns on NTFS, 10ms on exFAT) or exact
+    Ok(match requested.duration_since(stored) {
+        Ok(d) => d,
+        Err(e) => e.duration(),
+    })
+}
+
+/// Choose a `--modify-window` for a filesystem granularity.
+///
+/// Returns `None` when timestamps are stored precisely enough that rsync's
+/// default comparison works. rsync compares whole seconds, so only
+/// granularities coarser than one second (FAT's 2 seconds) need a window.
+pub fn modify_window_for(granularity: Duration) -> Option<u32> {
+    if granularity <= Duration::from_secs(1) {
+        None
+    } else {
+        Some(granularity.as_secs().div_ceil(2) as u32)
+    }
+}
+
+/// Probe the destination and return the modify window it needs.
+///
+/// The nearest existing ancestor of `dest` is probed, so this works before
+/// the destination directory has been created.
+pub fn detect_modify_window(dest: &Path) -> io::Result<Option<u32>> {
+    let dir = dest
+        .ancestors()
+        .find(|p| p.is_dir())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no existing parent directory"))?;
+    modify_window_with(&DirProbe::new(dir))
+}
+
+/// Return the modify window needed by the filesystem behind `probe`.
+pub fn modify_window_with(probe: &impl MtimeProbe) -> io::Result<Option<u32>> {
+    Ok(modify_window_for(detect_granularity(probe)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Probe that truncates timestamps to a fixed granularity.
+    struct MockProbe {
+        granularity: Duration,
+    }
+
+    impl MtimeProbe for MockProbe {
+        fn round_trip(&self, requested: SystemTime) -> io::Result<SystemTime> {
+            let since = requested.duration_since(UNIX_EPOCH).unwrap();
+            let g = self.granularity.as_nanos();
+            if g == 0 {
+                return Ok(requested);
+            }
+            let truncated = since.as_nanos() / g * g;
+            Ok(UNIX_EPOCH + Duration::from_nanos(truncated as u64))
+        }
+    }
+
+    /// Probe that always reports the same stored time.
+    struct FixedProbe(SystemTime);
+
+    impl MtimeProbe for FixedProbe {
+        fn round_trip(&self, _requested: SystemTime) -> io::Result<SystemTime> {
+            Ok(self.0)
+        }
+    }
+
+    fn detect(granularity: Duration) -> Duration {
+        detect_granularity(&MockProbe { granularity }).unwrap()
+    }
+
+    #[test]
+    fn test_detect_granularity() {
+        assert_eq!(detect(Duration::ZERO), Duration::ZERO);
+        assert_eq!(detect(Duration::from_secs(1)), Duration::from_secs(1));
+        assert_eq!(detect(Duration::from_secs(2)), Duration::from_secs(2));
+
+        // Sub-second granularity is reported as the observed loss
+        let ntfs = detect(Duration::from_nanos(100));
+        assert!(ntfs < Duration::from_secs(1));
+        assert!(!ntfs.is_zero());
+
+        // FAT may round up to the next even second instead of truncating
+        let rounded_up = FixedProbe(UNIX_EPOCH + Duration::from_secs(1_609_459_202));
+        assert_eq!(
+            detect_granularity(&rounded_up).unwrap(),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn test_modify_window_for() {
+        assert_eq!(modify_window_for(Duration::ZERO), None);
+        assert_eq!(modify_window_for(Duration::from_millis(10)), None);
+        assert_eq!(modify_window_for(Duration::from_secs(1)), None);
+        assert_eq!(modify_window_for(Duration::from_secs(2)), Some(1));
+        assert_eq!(modify_window_for(Duration::from_secs(4)), Some(2));
+    }
+
+    #[test]
+    fn test_modify_window_with_probe() {
+        let window = |granularity| modify_window_with(&MockProbe { granularity }).unwrap();
+        assert_eq!(window(Duration::ZERO), None);
+        assert_eq!(window(Duration::from_nanos(100)), None);
+        assert_eq!(window(Duration::from_secs(1)), None);
+        assert_eq!(window(Duration::from_secs(2)), Some(1));
+    }
+
+    #[test]
+    fn test_detect_modify_window_probes_existing_ancestor() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dest = temp_dir.path().join("not/yet/created");
+        // The window itself depends on the host filesystem; only check that
+        // the probe works before dest exists and cleans up after itself
+        detect_modify_window(&dest).unwrap();
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+}
@@ -23,6 +23,15 @@ pub struct RsyncStats {
     pub created: u64,
     pub deleted: u64,
     pub transferred: u64,
+    /// Total size of the transferred files in bytes.
+    #[serde(default)]
+    pub transferred_size: u64,
+    #[serde(default)]
+    pub bytes_sent: u64,
+    #[serde(default)]
+    pub bytes_received: u64,
+    #[serde(default)]
+    pub speedup: f64,
 }
 
 impl SyncPlan {
@@ -67,8 +76,28 @@ pub fn parse_rsync_stats(output: &str) -> Result<RsyncStats> {
     for line in output.lines() {
         if line.contains("Number of files:") {
             stats.files = parse_number(line).unwrap_or(0);
+        } else if line.contains("Number of created files:") {
+            stats.created = parse_number(line).unwrap_or(0);
+        } else if line.contains("Number of deleted files:") {
+            stats.deleted = parse_number(line).unwrap_or(0);
+        } else if line.contains("Number of regular files transferred:") {
+            stats.transferred = parse_number(line).unwrap_or(0);
         } else if line.contains("Total file size:") {
-            stats.total_size = parse_human_size(line).unwrap_or(0);
+            stats.total_size = parse_human_size(line)
+                .or_else(|| parse_stat_size(line))
+                .unwrap_or(0);
+        } else if line.contains("Total transferred file size:") {
+            stats.transferred_size = parse_stat_size(line).unwrap_or(0);
+        } else if line.contains("Total bytes sent:") {
+            stats.bytes_sent = parse_stat_size(line).unwrap_or(0);
+        } else if line.contains("Total bytes received:") {
+            stats.bytes_received = parse_stat_size(line).unwrap_or(0);
+        } else if let Some(pos) = line.find("speedup is ") {
+            stats.speedup = line[pos + "speedup is ".len()..]
+                .split_whitespace()
+                .next()
+                .and_then(|s| s.replace(',', "").parse().ok())
+                .unwrap_or(0.0);
         } else if line.contains("Created:") {
             stats.created = parse_num_files(line).unwrap_or(0);
         } else if line.contains("Deleted:") {
@@ -81,6 +110,133 @@ pub fn parse_rsync_stats(output: &str) -> Result<RsyncStats> {
     Ok(stats)
 }
 
+/// A single file in a sync diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffEntry {
+    pub path: String,
+    pub size: u64,
+}
+
+/// Files an rsync run would create, update, or delete.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncDiff {
+    pub created: Vec<DiffEntry>,
+    pub updated: Vec<DiffEntry>,
+    pub deleted: Vec<DiffEntry>,
+}
+
+/// Maximum number of paths listed per category when printing a diff.
+const DIFF_LIST_LIMIT: usize = 10;
+
+impl SyncDiff {
+    /// Total bytes that would be transferred (created + updated files).
+    pub fn transfer_bytes(&self) -> u64 {
+        self.created
+            .iter()
+            .chain(&self.updated)
+            .map(|e| e.size)
+            .sum()
+    }
+
+    /// Check if there are any changes.
+    pub fn has_changes(&self) -> bool {
+        !self.created.is_empty() || !self.updated.is_empty() || !self.deleted.is_empty()
+    }
+
+    /// One-line summary, e.g. "+120 new, ~15 updated, -3 deleted, 4.2GB".
+    pub fn summary(&self) -> String {
+        format!(
+            "+{} new, ~{} updated, -{} deleted, {}",
+            self.created.len(),
+            self.updated.len(),
+            self.deleted.len(),
+            format_size(self.transfer_bytes())
+        )
+    }
+
+    /// Print the diff in human-readable format.
+    pub fn print(&self, name: &str) {
+        println!("Sync Preview: {}", name);
+        if !self.has_changes() {
+            println!("  No changes - already up to date");
+            return;
+        }
+        println!("  {}", self.summary());
+
+        for (label, marker, entries) in [
+            ("New", '+', &self.created),
+            ("Updated", '~', &self.updated),
+            ("Deleted", '-', &self.deleted),
+        ] {
+            if entries.is_empty() {
+                continue;
+            }
+            println!();
+            println!("  {} ({}):", label, entries.len());
+            for entry in entries.iter().take(DIFF_LIST_LIMIT) {
+                println!("    {} {}", marker, entry.path);
+            }
+            if entries.len() > DIFF_LIST_LIMIT {
+                println!("    ... and {} more", entries.len() - DIFF_LIST_LIMIT);
+            }
+        }
+    }
+}
+
+/// rsync `--out-format` used for previews: itemized changes, size, and name.
+pub const DIFF_OUT_FORMAT: &str = "--out-format=%i %l %n";
+
+/// Parse itemized dry-run output produced with [`DIFF_OUT_FORMAT`].
+///
+/// Only regular files are counted; directory, symlink and attribute-only
+/// changes are ignored.
+pub fn parse_sync_diff(output: &str) -> SyncDiff {
+    static ITEM_RE: OnceLock<Regex> = OnceLock::new();
+    static DELETE_RE: OnceLock<Regex> = OnceLock::new();
+    let item_re = ITEM_RE.get_or_init(|| {
+        Regex::new(r"^([<>ch.])([fdLDS])(\S+) (\d[\d,]*) (.+)$").expect("invalid regex")
+    });
+    let delete_re = DELETE_RE
+        .get_or_init(|| Regex::new(r"^\*deleting\s+(?:(\d[\d,]*) )?(.+)$").expect("invalid regex"));
+
+    let mut diff = SyncDiff::default();
+
+    for line in output.lines() {
+        if let Some(caps) = delete_re.captures(line) {
+            let path = caps[2].to_string();
+            if path.ends_with('/') {
+                continue;
+            }
+            let size = caps
+                .get(1)
+                .and_then(|m| m.as_str().replace(',', "").parse().ok())
+                .unwrap_or(0);
+            diff.deleted.push(DiffEntry { path, size });
+            continue;
+        }
+
+        let Some(caps) = item_re.captures(line) else {
+            continue;
+        };
+        let update_type = &caps[1];
+        if &caps[2] != "f" || update_type == "." || update_type == "h" {
+            continue;
+        }
+
+        let entry = DiffEntry {
+            path: caps[5].to_string(),
+            size: caps[4].replace(',', "").parse().unwrap_or(0),
+        };
+        if caps[3].chars().all(|c| c == '+') {
+            diff.created.push(entry);
+        } else {
+            diff.updated.push(entry);
+        }
+    }
+
+    diff
+}
+
 fn parse_number(line: &str) -> Option<u64> {
     let pos = line.find(':')?;
     let rest = &line[pos + 2..];
@@ -114,6 +270,26 @@ fn parse_human_size(line: &str) -> Option<u64> {
 /// Cached regex for parsing human-readable sizes.
 static SIZE_REGEX: OnceLock<Regex> = OnceLock::new();
 
+/// Parse a `--stats` size value such as "1,234,567 bytes" or "1.23G bytes".
+///
+/// Bare unit suffixes are powers of 1000, as printed by `rsync -h`.
+fn parse_stat_size(line: &str) -> Option<u64> {
+    static STAT_SIZE_REGEX: OnceLock<Regex> = OnceLock::new();
+    let re = STAT_SIZE_REGEX
+        .get_or_init(|| Regex::new(r":\s*([\d,]+(?:\.\d+)?)([KMGTP])?").expect("invalid regex"));
+    let caps = re.captures(line)?;
+    let num: f64 = caps.get(1)?.as_str().replace(',', "").parse().ok()?;
+    let exponent = match caps.get(2).map(|m| m.as_str()) {
+        None => 0,
+        Some("K") => 1,
+        Some("M") => 2,
+        Some("G") => 3,
+        Some("T") => 4,
+        Some(_) => 5,
+    };
+    Some((num * 1000f64.powi(exponent)) as u64)
+}
+
 fn parse_num_files(line: &str) -> Option<u64> {
     // Find the number in "Created: 100 files" or "Deleted: 1,000 files"
     let parts: Vec<&str> = line.split_whitespace().collect();
@@ -179,10 +355,80 @@ mod tests {
         assert_eq!(parse_num_files("Deleted: 1,000 files"), Some(1000));
     }
 
+    #[test]
+    fn test_parse_rsync_stats_output() {
+        let output = "\
+Number of files: 1,234 (reg: 1,000, dir: 234)
+Number of created files: 10 (reg: 10)
+Number of deleted files: 2 (reg: 2)
+Number of regular files transferred: 15
+Total file size: 5,678,901 bytes
+Total transferred file size: 1.50M bytes
+Literal data: 0 bytes
+Matched data: 0 bytes
+File list size: 0
+Total bytes sent: 1,024
+Total bytes received: 48,213
+
+sent 1,024 bytes  received 48,213 bytes  19,694.80 bytes/sec
+total size is 5,678,901  speedup is 115.34 (DRY RUN)
+";
+        let stats = parse_rsync_stats(output).unwrap();
+        assert_eq!(stats.files, 1234);
+        assert_eq!(stats.created, 10);
+        assert_eq!(stats.deleted, 2);
+        assert_eq!(stats.transferred, 15);
+        assert_eq!(stats.total_size, 5_678_901);
+        assert_eq!(stats.transferred_size, 1_500_000);
+        assert_eq!(stats.bytes_sent, 1024);
+        assert_eq!(stats.bytes_received, 48_213);
+        assert_eq!(stats.speedup, 115.34);
+
+        let json: serde_json::Value = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["files"], 1234);
+        assert_eq!(json["transferred"], 15);
+        assert_eq!(json["total_size"], 5_678_901);
+        assert_eq!(json["transferred_size"], 1_500_000);
+        assert_eq!(json["speedup"], 115.34);
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(1024), "1.0KB");
         assert_eq!(format_size(1024 * 1024), "1.0MB");
         assert_eq!(format_size(500), "500B");
     }
+
+    #[test]
+    fn test_parse_sync_diff() {
+        let output = "\
+receiving incremental file list
+cd+++++++++ 4,096 ab/
+>f+++++++++ 1,048,576 ab/1abc.cif.gz
+>f+++++++++ 2048 ab/2abc.cif.gz
+>f.st...... 512 cd/1bcd.cif.gz
+.f...p..... 100 cd/2bcd.cif.gz
+*deleting   0 cd/3bcd.cif.gz
+*deleting   0 ef/
+";
+        let diff = parse_sync_diff(output);
+        assert_eq!(diff.created.len(), 2);
+        assert_eq!(diff.updated.len(), 1);
+        assert_eq!(diff.deleted.len(), 1);
+
+        assert_eq!(diff.created[0].path, "ab/1abc.cif.gz");
+        assert_eq!(diff.created[0].size, 1_048_576);
+        assert_eq!(diff.updated[0].path, "cd/1bcd.cif.gz");
+        assert_eq!(diff.deleted[0].path, "cd/3bcd.cif.gz");
+
+        assert_eq!(diff.transfer_bytes(), 1_048_576 + 2048 + 512);
+        assert_eq!(diff.summary(), "+2 new, ~1 updated, -1 deleted, 1.0MB");
+    }
+
+    #[test]
+    fn test_parse_sync_diff_no_changes() {
+        let diff = parse_sync_diff("receiving incremental file list\n");
+        assert!(!diff.has_changes());
+        assert_eq!(diff.summary(), "+0 new, ~0 updated, -0 deleted, 0B");
+    }
 }
@@ -124,6 +124,16 @@ pub enum RsyncPreset {
 }
 
 impl RsyncPreset {
+    /// Get all rsync flag presets.
+    pub fn all() -> &'static [RsyncPreset] {
+        &[
+            RsyncPreset::Safe,
+            RsyncPreset::Fast,
+            RsyncPreset::Minimal,
+            RsyncPreset::Conservative,
+        ]
+    }
+
     /// Convert preset name to enum variant.
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
@@ -195,17 +205,10 @@ pub fn get_rsync_preset(name: &str) -> Option<RsyncFlags> {
 
 /// List all available rsync flag presets with their descriptions.
 pub fn list_rsync_presets() {
-    let presets = [
-        RsyncPreset::Safe,
-        RsyncPreset::Fast,
-        RsyncPreset::Minimal,
-        RsyncPreset::Conservative,
-    ];
-
     println!("Available rsync flag presets:");
     println!();
 
-    for preset in &presets {
+    for preset in RsyncPreset::all() {
         println!("Name: {}", preset.as_str());
         println!("  Description: {}", preset.description());
         let flags = preset.to_flags();
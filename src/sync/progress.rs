@@ -0,0 +1,296 @@
+//! Throttled textual progress for rsync `--info=progress2` output.
+
+use crate::error::Result;
+use pdb_sync::utils::ProgressThrottle;
+use regex::Regex;
+use std::io::Write;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// A single aggregate progress update from rsync `--info=progress2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsyncProgress {
+    /// Bytes transferred so far, as printed by rsync (may be human-readable).
+    pub transferred: String,
+    pub percent: u8,
+    pub rate: String,
+    /// Files checked so far and total files, from `to-chk=remaining/total`.
+    pub files: Option<(u64, u64)>,
+}
+
+impl RsyncProgress {
+    /// Format as a single log-friendly line.
+    pub fn to_line(&self, name: &str) -> String {
+        match self.files {
+            Some((done, total)) => format!(
+                "[{}] transferred {}, {}/{} files ({}%), {}",
+                name, self.transferred, done, total, self.percent, self.rate
+            ),
+            None => format!(
+                "[{}] transferred {} ({}%), {}",
+                name, self.transferred, self.percent, self.rate
+            ),
+        }
+    }
+}
+
+/// Parse a progress2 line such as
+/// `  1.23G  45%   12.34MB/s    0:01:23 (xfr#3, to-chk=10/20)`.
+pub fn parse_progress2_line(line: &str) -> Option<RsyncProgress> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    static CHK_RE: OnceLock<Regex> = OnceLock::new();
+    let re =
+        RE.get_or_init(|| Regex::new(r"^\s*([\d.,]+[KMGTP]?)\s+(\d{1,3})%\s+(\S+/s)").unwrap());
+    let chk_re = CHK_RE.get_or_init(|| Regex::new(r"to-chk=(\d+)/(\d+)").unwrap());
+
+    let caps = re.captures(line)?;
+    let files = chk_re.captures(line).and_then(|c| {
+        let remaining: u64 = c[1].parse().ok()?;
+        let total: u64 = c[2].parse().ok()?;
+        Some((total.saturating_sub(remaining), total))
+    });
+
+    Some(RsyncProgress {
+        transferred: caps[1].to_string(),
+        percent: caps[2].parse().ok()?,
+        rate: caps[3].to_string(),
+        files,
+    })
+}
+
+/// Collector for the `--stats` summary rsync prints at the end of a run.
+///
+/// Only lines from "Number of files:" onwards are kept, so memory stays
+/// bounded however much per-file output precedes the summary.
+#[derive(Debug, Default)]
+struct StatsBlock {
+    text: String,
+}
+
+impl StatsBlock {
+    fn push(&mut self, line: &str) {
+        if self.text.is_empty() && !line.contains("Number of files:") {
+            return;
+        }
+        self.text.push_str(line.trim_end());
+        self.text.push('\n');
+    }
+}
+
+/// Relay rsync output, printing aggregate progress at most once per `interval`.
+///
+/// Progress updates (separated by carriage returns) are throttled; other
+/// output lines are passed through with a `[name]` prefix. A held-back
+/// progress update is printed before the next other line, or when the
+/// stream ends.
+///
+/// Returns the `--stats` summary, or an empty string if there was none.
+pub async fn relay_progress<R>(reader: R, name: &str, interval: Duration) -> Result<String>
+where
+    R: AsyncRead + Unpin,
+{
+    relay_progress_to(reader, name, interval, &mut std::io::stdout()).await
+}
+
+/// Like [`relay_progress`], writing the relayed lines to `out`.
+async fn relay_progress_to<R, W>(
+    mut reader: R,
+    name: &str,
+    interval: Duration,
+    out: &mut W,
+) -> Result<String>
+where
+    R: AsyncRead + Unpin,
+    W: Write,
+{
+    let mut throttle = ProgressThrottle::new(interval);
+    let mut pending: Option<RsyncProgress> = None;
+    let mut stats = StatsBlock::default();
+    let mut segment = Vec::new();
+    let mut buf = [0u8; 8192];
+
+    let mut handle_segment =
+        |segment: &[u8], pending: &mut Option<RsyncProgress>, out: &mut W| -> Result<()> {
+            let text = String::from_utf8_lossy(segment);
+            if text.trim().is_empty() {
+                return Ok(());
+            }
+            stats.push(&text);
+            match parse_progress2_line(&text) {
+                Some(progress) => {
+                    if throttle.ready() {
+                        writeln!(out, "{}", progress.to_line(name))?;
+                        *pending = None;
+                    } else {
+                        *pending = Some(progress);
+                    }
+                }
+                None => {
+                    // Keep the last progress update ahead of the lines after it
+                    if let Some(progress) = pending.take() {
+                        writeln!(out, "{}", progress.to_line(name))?;
+                    }
+                    writeln!(out, "[{}] {}", name, text.trim_end())?;
+                }
+            }
+            Ok(())
+        };
+
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        for &byte in &buf[..n] {
+            if byte == b'\r' || byte == b'\n' {
+                handle_segment(&segment, &mut pending, out)?;
+                segment.clear();
+            } else {
+                segment.push(byte);
+            }
+        }
+    }
+    handle_segment(&segment, &mut pending, out)?;
+
+    if let Some(progress) = pending {
+        writeln!(out, "{}", progress.to_line(name))?;
+    }
+    out.flush()?;
+
+    Ok(stats.text)
+}
+
+/// Copy rsync output to stdout unchanged, returning the `--stats` summary.
+pub async fn tee_stats<R>(mut reader: R) -> Result<String>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut stats = StatsBlock::default();
+    let mut segment = Vec::new();
+    let mut buf = [0u8; 8192];
+
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(&buf[..n])?;
+        stdout.flush()?;
+        for &byte in &buf[..n] {
+            if byte == b'\r' || byte == b'\n' {
+                stats.push(&String::from_utf8_lossy(&segment));
+                segment.clear();
+            } else {
+                segment.push(byte);
+            }
+        }
+    }
+    stats.push(&String::from_utf8_lossy(&segment));
+
+    Ok(stats.text)
+}
+
+/// Relay output to stderr, prefixing each line with `[name]`.
+pub async fn relay_prefixed_stderr<R>(reader: R, name: &str) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    relay_prefixed_to(reader, name, &mut std::io::stderr()).await
+}
+
+/// Like [`relay_prefixed_stderr`], writing the prefixed lines to `out`.
+async fn relay_prefixed_to<R, W>(reader: R, name: &str, out: &mut W) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: Write,
+{
+    use tokio::io::AsyncBufReadExt;
+
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if !line.trim().is_empty() {
+            writeln!(out, "[{}] {}", name, line.trim_end())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_progress2_line() {
+        let p = parse_progress2_line(
+            "          1.23G  45%   12.34MB/s    0:01:23 (xfr#3, to-chk=10/20)",
+        )
+        .unwrap();
+        assert_eq!(p.transferred, "1.23G");
+        assert_eq!(p.percent, 45);
+        assert_eq!(p.rate, "12.34MB/s");
+        assert_eq!(p.files, Some((10, 20)));
+        assert_eq!(
+            p.to_line("emdb"),
+            "[emdb] transferred 1.23G, 10/20 files (45%), 12.34MB/s"
+        );
+
+        let p = parse_progress2_line("    32,768   0%    0.00kB/s    0:00:00").unwrap();
+        assert_eq!(p.transferred, "32,768");
+        assert_eq!(p.files, None);
+
+        assert!(parse_progress2_line("receiving incremental file list").is_none());
+        assert!(parse_progress2_line("pdb/ab/1abc.cif.gz").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_relay_progress_returns_stats_block() {
+        let output: &[u8] = b"receiving incremental file list\r  1.00K 50%  1.00kB/s  0:00:01\r  2.00K 100%  1.00kB/s  0:00:02\n\nNumber of files: 3\nTotal bytes sent: 10\n";
+        let stats = relay_progress_to(output, "test", Duration::from_secs(60), &mut Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(stats, "Number of files: 3\nTotal bytes sent: 10\n");
+    }
+
+    #[tokio::test]
+    async fn test_relay_progress_prefixes_lines() {
+        let output: &[u8] = b"receiving incremental file list\n\
+ab/1abc.cif.gz\n\
+  1.00K 50%  1.00kB/s  0:00:01 (xfr#1, to-chk=1/2)\r\
+  2.00K 100%  2.00kB/s  0:00:02 (xfr#2, to-chk=0/2)\n\
+\n\
+Number of files: 2\n\
+Total bytes received: 2,048\n";
+        let mut out = Vec::new();
+        let stats = relay_progress_to(output, "emdb", Duration::from_secs(60), &mut out)
+            .await
+            .unwrap();
+
+        // The first progress update is printed immediately; the second is
+        // throttled and flushed before the stats block that follows it.
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[emdb] receiving incremental file list\n\
+[emdb] ab/1abc.cif.gz\n\
+[emdb] transferred 1.00K, 1/2 files (50%), 1.00kB/s\n\
+[emdb] transferred 2.00K, 2/2 files (100%), 2.00kB/s\n\
+[emdb] Number of files: 2\n\
+[emdb] Total bytes received: 2,048\n"
+        );
+        assert_eq!(stats, "Number of files: 2\nTotal bytes received: 2,048\n");
+    }
+
+    #[tokio::test]
+    async fn test_relay_prefixed_stderr_lines() {
+        let stderr: &[u8] =
+            b"rsync: [sender] link_stat \"/x\" failed: No such file\n\n  \nrsync error: some files could not be transferred (code 23)";
+        let mut out = Vec::new();
+        relay_prefixed_to(stderr, "pdbj", &mut out).await.unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[pdbj] rsync: [sender] link_stat \"/x\" failed: No such file\n\
+[pdbj] rsync error: some files could not be transferred (code 23)\n"
+        );
+    }
+}
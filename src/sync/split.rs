@@ -0,0 +1,235 @@
+//! Splitting a sync into per-directory subtrees for parallel rsync runs.
+
+use crate::sync::listing::RemoteEntry;
+use crate::sync::plan::RsyncStats;
+use crate::sync::RsyncFlags;
+use std::path::{Path, PathBuf};
+
+/// One rsync invocation covering part of a split sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtreeTask {
+    /// Top-level directory synced by this task, or `None` for the files
+    /// directly under the source root.
+    pub dir: Option<String>,
+    pub url: String,
+    pub dest: PathBuf,
+}
+
+impl SubtreeTask {
+    /// Short label for log prefixes, e.g. "structures/ab".
+    pub fn label(&self, name: &str) -> String {
+        match self.dir {
+            Some(ref dir) => format!("{}/{}", name, dir),
+            None => format!("{}/.", name),
+        }
+    }
+
+    /// Rsync flags for this task, derived from the flags of the whole sync.
+    ///
+    /// Absolute partial and backup directories are shared by every rsync
+    /// process, so each subtree gets its own subdirectory to avoid
+    /// collisions. The root task excludes directories, which are covered by
+    /// the subtree tasks.
+    pub fn flags(&self, flags: &RsyncFlags) -> RsyncFlags {
+        let mut flags = flags.clone();
+        let bucket = self.dir.as_deref().unwrap_or(".root");
+
+        for dir in [&mut flags.partial_dir, &mut flags.backup_dir]
+            .into_iter()
+            .flatten()
+        {
+            if Path::new(dir.as_str()).is_absolute() {
+                *dir = Path::new(dir.as_str())
+                    .join(bucket)
+                    .to_string_lossy()
+                    .into_owned();
+            }
+        }
+
+        if self.dir.is_none() {
+            flags.exclude.push("*/".to_string());
+        }
+        flags
+    }
+}
+
+/// Split a sync of `url` into `dest` into one task per top-level directory.
+///
+/// `entries` is the non-recursive `--list-only` listing of `url`. A root
+/// task for top-level files is added only when the listing contains any.
+pub fn split_subtrees(url: &str, dest: &Path, entries: &[RemoteEntry]) -> Vec<SubtreeTask> {
+    let base = url.trim_end_matches('/');
+    let mut dirs: Vec<&str> = entries
+        .iter()
+        .filter(|e| e.is_dir && !e.name.contains('/'))
+        .map(|e| e.name.as_str())
+        .collect();
+    dirs.sort_unstable();
+    dirs.dedup();
+
+    let mut tasks: Vec<SubtreeTask> = dirs
+        .into_iter()
+        .map(|dir| SubtreeTask {
+            dir: Some(dir.to_string()),
+            url: format!("{}/{}/", base, dir),
+            dest: dest.join(dir),
+        })
+        .collect();
+
+    if entries.iter().any(|e| !e.is_dir) {
+        tasks.push(SubtreeTask {
+            dir: None,
+            url: format!("{}/", base),
+            dest: dest.to_path_buf(),
+        });
+    }
+
+    tasks
+}
+
+/// Sum the statistics of several rsync runs.
+///
+/// `speedup` is recomputed from the totals the way rsync computes it
+/// (total size over bytes sent and received), and left at zero when no
+/// bytes went over the wire.
+pub fn aggregate_stats<'a>(stats: impl IntoIterator<Item = &'a RsyncStats>) -> RsyncStats {
+    let mut total = stats
+        .into_iter()
+        .fold(RsyncStats::default(), |mut total, s| {
+            total.files += s.files;
+            total.total_size += s.total_size;
+            total.created += s.created;
+            total.deleted += s.deleted;
+            total.transferred += s.transferred;
+            total.transferred_size += s.transferred_size;
+            total.bytes_sent += s.bytes_sent;
+            total.bytes_received += s.bytes_received;
+            total
+        });
+
+    let wire_bytes = total.bytes_sent + total.bytes_received;
+    if wire_bytes > 0 {
+        total.speedup = total.total_size as f64 / wire_bytes as f64;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::listing::parse_list_only;
+
+    const LISTING: &str = "\
+drwxr-xr-x          4,096 2024/01/15 10:30:00 .
+drwxr-xr-x          4,096 2024/01/15 10:30:00 ab
+drwxr-xr-x          4,096 2024/01/14 09:00:00 00
+-rw-r--r--            512 2024/01/10 08:00:00 README
+drwxr-xr-x          4,096 2024/01/13 12:00:00 zz
+";
+
+    #[test]
+    fn test_split_subtrees() {
+        let entries = parse_list_only(LISTING);
+        let tasks = split_subtrees(
+            "rsync.wwpdb.org::ftp/data/structures/divided/mmCIF/",
+            Path::new("/data/pdb/mmCIF"),
+            &entries,
+        );
+
+        let dirs: Vec<_> = tasks.iter().map(|t| t.dir.as_deref()).collect();
+        assert_eq!(dirs, vec![Some("00"), Some("ab"), Some("zz"), None]);
+
+        assert_eq!(
+            tasks[1].url,
+            "rsync.wwpdb.org::ftp/data/structures/divided/mmCIF/ab/"
+        );
+        assert_eq!(tasks[1].dest, PathBuf::from("/data/pdb/mmCIF/ab"));
+        assert_eq!(tasks[1].label("mmcif"), "mmcif/ab");
+
+        let root = &tasks[3];
+        assert_eq!(
+            root.url,
+            "rsync.wwpdb.org::ftp/data/structures/divided/mmCIF/"
+        );
+        assert_eq!(root.dest, PathBuf::from("/data/pdb/mmCIF"));
+    }
+
+    #[test]
+    fn test_split_subtrees_without_top_level_files() {
+        let entries: Vec<_> = parse_list_only(LISTING)
+            .into_iter()
+            .filter(|e| e.is_dir)
+            .collect();
+        let tasks = split_subtrees("host::module", Path::new("dest"), &entries);
+        assert_eq!(tasks.len(), 3);
+        assert!(tasks.iter().all(|t| t.dir.is_some()));
+        assert_eq!(tasks[0].url, "host::module/00/");
+    }
+
+    #[test]
+    fn test_subtree_flags_avoid_shared_dirs() {
+        let flags = RsyncFlags {
+            partial: true,
+            partial_dir: Some("/tmp/rsync-partial".to_string()),
+            backup: true,
+            backup_dir: Some(".backup".to_string()),
+            ..Default::default()
+        };
+        let entries = parse_list_only(LISTING);
+        let tasks = split_subtrees("host::module/", Path::new("dest"), &entries);
+
+        let ab = tasks[1].flags(&flags);
+        assert_eq!(ab.partial_dir.as_deref(), Some("/tmp/rsync-partial/ab"));
+        // Relative directories already live inside each subtree's destination
+        assert_eq!(ab.backup_dir.as_deref(), Some(".backup"));
+        assert!(ab.exclude.is_empty());
+
+        let root = tasks[3].flags(&flags);
+        assert_eq!(
+            root.partial_dir.as_deref(),
+            Some("/tmp/rsync-partial/.root")
+        );
+        assert_eq!(root.exclude, vec!["*/".to_string()]);
+    }
+
+    #[test]
+    fn test_aggregate_stats() {
+        let a = RsyncStats {
+            files: 10,
+            transferred: 2,
+            transferred_size: 100,
+            ..Default::default()
+        };
+        let b = RsyncStats {
+            files: 5,
+            transferred: 1,
+            transferred_size: 50,
+            ..Default::default()
+        };
+        let total = aggregate_stats([&a, &b]);
+        assert_eq!(total.files, 15);
+        assert_eq!(total.transferred, 3);
+        assert_eq!(total.transferred_size, 150);
+        assert_eq!(total.speedup, 0.0);
+    }
+
+    #[test]
+    fn test_aggregate_stats_speedup() {
+        let a = RsyncStats {
+            total_size: 6_000,
+            bytes_sent: 100,
+            bytes_received: 200,
+            speedup: 20.0,
+            ..Default::default()
+        };
+        let b = RsyncStats {
+            total_size: 2_000,
+            bytes_sent: 50,
+            bytes_received: 50,
+            speedup: 20.0,
+            ..Default::default()
+        };
+        let total = aggregate_stats([&a, &b]);
+        assert_eq!(total.speedup, 20.0);
+    }
+}
@@ -1,8 +1,9 @@
 //! Sync configuration validation.
 
-use crate::config::Config;
+use crate::config::loader::ConfigLayer;
+use crate::config::ConfigLoader;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Validation result for a configuration check.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +19,9 @@ pub struct ValidationCheck {
     pub status: ValidationStatus,
     pub message: String,
     pub fixable: bool,
+    /// Config file the checked value comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
 }
 
 /// Status of a validation check.
@@ -37,66 +41,64 @@ impl ValidationResult {
             .any(|c| matches!(c.status, ValidationStatus::Error))
     }
 
-    /// Print validation results in human-readable format.
-    pub fn print(&self) {
-        if self.valid {
-            println!("Configuration is valid!");
-            return;
-        }
-
-        println!("\nValidation Results:\n");
-
-        for check in &self.checks {
-            let status = match check.status {
-                ValidationStatus::Pass => "✓",
-                ValidationStatus::Warning => "⚠",
-                ValidationStatus::Error => "✗",
-            };
-
-            println!("{} {}", status, check.name);
-
-            if !check.message.is_empty() {
-                println!("  {}", check.message);
-            }
-
-            if check.fixable {
-                println!("  (This issue can be auto-fixed with --fix)");
-            }
-
-            println!();
-        }
+    /// Check if there are any warnings.
+    pub fn has_warnings(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|c| matches!(c.status, ValidationStatus::Warning))
+    }
 
+    /// Check whether validation passes.
+    ///
+    /// Errors always fail; in strict mode warnings fail as well.
+    pub fn passes(&self, strict: bool) -> bool {
         if self.has_errors() {
-            println!("Validation failed with errors.");
-        } else {
-            println!("Validation passed with warnings.");
+            return false;
         }
+        !strict || !self.has_warnings()
     }
 }
 
-/// Validate the entire configuration.
-pub fn validate_config(config: &Config) -> ValidationResult {
+/// Validate every config file that makes up the effective configuration.
+///
+/// Each sync target is checked in the file that defines it, and the check
+/// records that file. A target redefined by a later file is only checked
+/// there, since that definition is the one sync uses. Flags are checked against the merged `sync.defaults`,
+/// and whether any sync targets exist at all is checked on the merged config.
+pub fn validate_layers(layers: &[ConfigLayer]) -> ValidationResult {
+    let merged = ConfigLoader::merge_layers(layers);
     let mut checks = Vec::new();
 
-    // Validate custom sync configs
-    for (name, custom_config) in &config.sync.custom {
-        checks.push(validate_custom_config_name(name));
-        checks.push(validate_custom_config_url(&custom_config.url));
-        checks.push(validate_custom_config_dest(&custom_config.dest));
-        checks.push(validate_custom_config_flags(
-            name,
-            custom_config,
-            config.sync.defaults.as_ref(),
-        ));
+    // Validate custom sync configs, skipping targets a later layer replaces
+    for (i, layer) in layers.iter().enumerate() {
+        for (name, custom_config) in &layer.config.sync.custom {
+            let shadowed = layers[i + 1..]
+                .iter()
+                .any(|later| later.config.sync.custom.contains_key(name));
+            if shadowed {
+                continue;
+            }
+            let layer_checks = [
+                validate_custom_config_name(name),
+                validate_custom_config_url(&custom_config.url),
+                validate_custom_config_dest(&custom_config.dest),
+                validate_custom_config_flags(name, custom_config, merged.sync.defaults.as_ref()),
+            ];
+            checks.extend(layer_checks.into_iter().map(|check| ValidationCheck {
+                file: Some(layer.path.clone()),
+                ..check
+            }));
+        }
     }
 
     // Check if there are any custom configs
-    if config.sync.custom.is_empty() {
+    if merged.sync.custom.is_empty() {
         checks.push(ValidationCheck {
             name: "Custom sync configs".to_string(),
             status: ValidationStatus::Warning,
             message: "No custom sync configs defined".to_string(),
             fixable: false,
+            file: None,
         });
     }
 
@@ -114,6 +116,7 @@ fn validate_custom_config_name(name: &str) -> ValidationCheck {
             status: ValidationStatus::Error,
             message: "Name cannot be empty".to_string(),
             fixable: false,
+            file: None,
         };
     }
 
@@ -123,6 +126,7 @@ fn validate_custom_config_name(name: &str) -> ValidationCheck {
             status: ValidationStatus::Error,
             message: "Name cannot contain spaces or path separators".to_string(),
             fixable: false,
+            file: None,
         };
     }
 
@@ -131,6 +135,7 @@ fn validate_custom_config_name(name: &str) -> ValidationCheck {
         status: ValidationStatus::Pass,
         message: String::new(),
         fixable: false,
+        file: None,
     }
 }
 
@@ -144,12 +149,14 @@ fn validate_custom_config_url(url: &str) -> ValidationCheck {
             status: ValidationStatus::Pass,
             message: String::new(),
             fixable: false,
+            file: None,
         },
         Err(e) => ValidationCheck {
             name: format!("URL '{}'", url),
             status: ValidationStatus::Error,
             message: e.to_string(),
             fixable: false,
+            file: None,
         },
     }
 }
@@ -162,6 +169,7 @@ fn validate_custom_config_dest(dest: &str) -> ValidationCheck {
             status: ValidationStatus::Error,
             message: "Path traversal not allowed (contains '..')".to_string(),
             fixable: false,
+            file: None,
         };
     }
 
@@ -172,6 +180,7 @@ fn validate_custom_config_dest(dest: &str) -> ValidationCheck {
             status: ValidationStatus::Warning,
             message: "Absolute path detected - should be relative subpath".to_string(),
             fixable: false,
+            file: None,
         };
     }
 
@@ -180,6 +189,7 @@ fn validate_custom_config_dest(dest: &str) -> ValidationCheck {
         status: ValidationStatus::Pass,
         message: String::new(),
         fixable: false,
+        file: None,
     }
 }
 
@@ -212,6 +222,7 @@ fn validate_custom_config_flags(
             status: ValidationStatus::Pass,
             message: String::new(),
             fixable: false,
+            file: None,
         }
     } else {
         ValidationCheck {
@@ -219,6 +230,7 @@ fn validate_custom_config_flags(
             status: ValidationStatus::Error,
             message: issues.join("; "),
             fixable: false,
+            file: None,
         }
     }
 }
@@ -254,9 +266,109 @@ mod tests {
         assert!(matches!(check.status, ValidationStatus::Error));
     }
 
+    fn layer(path: &str, toml_str: &str) -> ConfigLayer {
+        ConfigLayer {
+            path: PathBuf::from(path),
+            config: toml::from_str(toml_str).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_warnings_fail_only_in_strict_mode() {
+        let mut config = crate::config::Config::default();
+        config.sync.custom.insert(
+            "structures".to_string(),
+            toml::from_str(
+                r#"
+                url = "rsync.wwpdb.org::ftp_data/structures/"
+                dest = "/absolute/structures"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        let result = validate_layers(&[ConfigLayer {
+            path: PathBuf::from("config.toml"),
+            config,
+        }]);
+        assert!(!result.has_errors());
+        assert!(result.has_warnings());
+        assert!(result.passes(false));
+        assert!(!result.passes(true));
+    }
+
     #[test]
     fn test_validate_dest_absolute_path_warning() {
         let check = validate_custom_config_dest("/absolute/path");
         assert!(matches!(check.status, ValidationStatus::Warning));
     }
+
+    #[test]
+    fn test_validate_layers_reports_file() {
+        let layers = [
+            layer(
+                "/home/user/.config/pdb-sync/config.toml",
+                r#"
+[sync.custom.structures]
+url = "rsync.wwpdb.org::ftp_data/structures/"
+dest = "structures"
+"#,
+            ),
+            // The project file's targets are attributed to the project file
+            layer(
+                "/work/project/.pdb-sync.toml",
+                r#"
+[sync.custom.local]
+url = "not-a-url"
+dest = "local"
+"#,
+            ),
+        ];
+
+        let result = validate_layers(&layers);
+        assert!(!result.valid);
+        let bad_url = result
+            .checks
+            .iter()
+            .find(|c| matches!(c.status, ValidationStatus::Error))
+            .unwrap();
+        assert_eq!(bad_url.name, "URL 'not-a-url'");
+        assert_eq!(
+            bad_url.file,
+            Some(PathBuf::from("/work/project/.pdb-sync.toml"))
+        );
+        assert!(result.checks.iter().all(|c| c.file.is_some()));
+
+        // With no targets in any layer, the empty warning is reported
+        let result = validate_layers(&[layer("/work/project/.pdb-sync.toml", "")]);
+        assert!(result.has_warnings());
+    }
+
+    #[test]
+    fn test_validate_layers_skips_shadowed_targets() {
+        let layers = [
+            layer(
+                "/home/user/.config/pdb-sync/config.toml",
+                r#"
+[sync.custom.structures]
+url = "not-a-url"
+dest = "structures"
+"#,
+            ),
+            layer(
+                "/work/project/.pdb-sync.toml",
+                r#"
+[sync.custom.structures]
+url = "rsync.wwpdb.org::ftp_data/structures/"
+dest = "structures"
+"#,
+            ),
+        ];
+
+        let result = validate_layers(&layers);
+        assert!(result.valid);
+        assert!(!result.has_errors());
+        let project = Some(PathBuf::from("/work/project/.pdb-sync.toml"));
+        assert!(result.checks.iter().all(|c| c.file == project));
+    }
 }
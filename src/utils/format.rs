@@ -1,4 +1,18 @@
-//! Formatting utilities for human-readable output.
+//! Formatting utilities for human-readable and JSON output.
+
+use serde::Serialize;
+
+/// Serialize a value for `--json` output.
+///
+/// Output is compact (a single line, suitable for piping) unless `pretty`
+/// is set, in which case it is indented for reading.
+pub fn to_json<T: Serialize + ?Sized>(value: &T, pretty: bool) -> serde_json::Result<String> {
+    if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    }
+}
 
 /// Format bytes as human-readable size (e.g., "1.5 GB", "234 KB", "100 B").
 pub fn human_bytes(bytes: u64) -> String {
@@ -24,6 +38,22 @@ pub fn human_bytes(bytes: u64) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_to_json_compact_and_pretty() {
+        let value = serde_json::json!({"name": "mmCIF", "extensions": ["cif", "cif.gz"]});
+
+        let compact = to_json(&value, false).unwrap();
+        assert!(!compact.contains('\n'));
+        assert_eq!(compact, r#"{"extensions":["cif","cif.gz"],"name":"mmCIF"}"#);
+
+        let pretty = to_json(&value, true).unwrap();
+        assert!(pretty.lines().count() > 1);
+        assert!(pretty.contains("\n  \"name\": \"mmCIF\""));
+
+        let reparsed: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(reparsed, value);
+    }
+
     #[test]
     fn test_human_bytes_bytes() {
         assert_eq!(human_bytes(0), "0 B");
@@ -2,6 +2,8 @@
 
 pub mod colors;
 pub mod format;
+pub mod progress;
 
 pub use colors::*;
 pub use format::*;
+pub use progress::*;
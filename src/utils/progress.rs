@@ -0,0 +1,68 @@
+//! Throttling for periodic textual progress output.
+
+use std::time::{Duration, Instant};
+
+/// Rate limiter that allows an event at most once per interval.
+///
+/// The first event is always allowed so that progress is visible immediately.
+#[derive(Debug, Clone)]
+pub struct ProgressThrottle {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl ProgressThrottle {
+    /// Create a throttle with the given minimum interval between events.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Check whether an event may be emitted now, recording it if so.
+    pub fn ready(&mut self) -> bool {
+        self.ready_at(Instant::now())
+    }
+
+    /// Check whether an event may be emitted at `now`, recording it if so.
+    pub fn ready_at(&mut self, now: Instant) -> bool {
+        match self.last {
+            Some(last) if now.saturating_duration_since(last) < self.interval => false,
+            _ => {
+                self.last = Some(now);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_event_allowed() {
+        let mut throttle = ProgressThrottle::new(Duration::from_secs(60));
+        assert!(throttle.ready());
+        assert!(!throttle.ready());
+    }
+
+    #[test]
+    fn test_simulated_run_respects_interval() {
+        let interval = Duration::from_secs(1);
+        let mut throttle = ProgressThrottle::new(interval);
+        let start = Instant::now();
+
+        // Progress updates every 70ms for 10 seconds
+        let emitted: Vec<Instant> = (0..=142)
+            .map(|i| start + Duration::from_millis(i * 70))
+            .filter(|&t| throttle.ready_at(t))
+            .collect();
+
+        assert!(emitted.len() >= 9 && emitted.len() <= 11);
+        for pair in emitted.windows(2) {
+            assert!(pair[1] - pair[0] >= interval);
+        }
+    }
+}